@@ -0,0 +1,45 @@
+// compares the two 2022 day 23 engines on the same input
+// HashSet based `Day23` vs chunked sparse grid based `Day23Performance`
+//
+// cargo bench --bench 2022_23_variants -- --save-baseline before
+// ...optimise...
+// cargo bench --bench 2022_23_variants -- --baseline before
+use advent_of_code_2023::days::y2022::day23::Day23;
+use advent_of_code_2023::days::y2022::day23_performance::Day23Performance;
+use advent_of_code_2023::input::load_input;
+use advent_of_code_2023::solution::Solution;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::hint::black_box;
+use std::time::Duration;
+
+fn bench_variant<S: Solution>(c: &mut Criterion, name: &str, input: &str) {
+    let parsed = S::parse(input).unwrap();
+
+    c.benchmark_group("2022_23 parse")
+        .bench_function(BenchmarkId::from_parameter(name), |b| {
+            b.iter(|| S::parse(black_box(input)))
+        });
+
+    c.benchmark_group("2022_23 part1")
+        .bench_function(BenchmarkId::from_parameter(name), |b| {
+            b.iter(|| S::part1(black_box(&parsed)))
+        });
+
+    // the HashSet engine takes seconds per iteration here, keep the sample count down
+    c.benchmark_group("2022_23 part2")
+        .sample_size(10)
+        .measurement_time(Duration::from_secs(30))
+        .bench_function(BenchmarkId::from_parameter(name), |b| {
+            b.iter(|| S::part2(black_box(&parsed)))
+        });
+}
+
+fn bench_2022_23(c: &mut Criterion) {
+    let input = load_input(2022, 23);
+
+    bench_variant::<Day23>(c, "hashset", &input);
+    bench_variant::<Day23Performance>(c, "sparse_grid", &input);
+}
+
+criterion_group!(benches, bench_2022_23);
+criterion_main!(benches);
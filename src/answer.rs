@@ -0,0 +1,151 @@
+// what every part returns, once it's been through the runner
+// days keep their natural return types (u32, usize, i64, ...) & convert with .into()
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone)]
+pub enum Answer {
+    I64(i64),
+    U64(u64),
+    I128(i128),
+    U128(u128),
+    // some puzzles spell out their answer, i.e. a code or a word
+    Text(String),
+}
+
+impl Answer {
+    // (negative, magnitude), so numbers compare by value whichever variant holds them
+    fn numeric(&self) -> Option<(bool, u128)> {
+        match self {
+            Answer::I64(value) => Some((*value < 0, value.unsigned_abs() as u128)),
+            Answer::U64(value) => Some((false, *value as u128)),
+            Answer::I128(value) => Some((*value < 0, value.unsigned_abs())),
+            Answer::U128(value) => Some((false, *value)),
+            Answer::Text(_) => None,
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::I64(value) => write!(f, "{value}"),
+            Answer::U64(value) => write!(f, "{value}"),
+            Answer::I128(value) => write!(f, "{value}"),
+            Answer::U128(value) => write!(f, "{value}"),
+            Answer::Text(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            _ => self.numeric().is_some() && self.numeric() == other.numeric(),
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+// stored answers (answers.toml, the site) are text
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        let other = other.trim();
+
+        match self {
+            Answer::Text(text) => text == other,
+            _ => other
+                .parse()
+                .map(Answer::U128)
+                .or_else(|_| other.parse().map(Answer::I128))
+                .is_ok_and(|other| *self == other),
+        }
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+macro_rules! answer_from {
+    ($variant:ident, $($int:ty),+) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(value: $int) -> Self {
+                    Answer::$variant(value.into())
+                }
+            }
+        )+
+    };
+}
+
+answer_from!(I64, i8, i16, i32, i64);
+answer_from!(U64, u8, u16, u32, u64);
+answer_from!(I128, i128);
+answer_from!(U128, u128);
+
+// usize & isize don't have From conversions into u64/i64, but fit on every supported platform
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::U64(value as u64)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(value: isize) -> Self {
+        Answer::I64(value as i64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(142u32).to_string(), "142");
+        assert_eq!(Answer::from(-3i64).to_string(), "-3");
+        assert_eq!(
+            Answer::from(u128::MAX).to_string(),
+            "340282366920938463463374607431768211455"
+        );
+        assert_eq!(Answer::from("EZJR").to_string(), "EZJR");
+    }
+
+    #[test]
+    fn test_equality() {
+        // the same number, whichever type the day returned it as
+        assert_eq!(Answer::from(5u32), Answer::from(5i64));
+        assert_eq!(Answer::from(5usize), Answer::from(5u128));
+        assert_eq!(Answer::from(0i64), Answer::from(0u64));
+        assert_ne!(Answer::from(-5i64), Answer::from(5u64));
+        assert_ne!(Answer::from(i128::MIN), Answer::from(u128::MAX));
+
+        // text only equals text
+        assert_eq!(Answer::from("abc"), Answer::from("abc".to_string()));
+        assert_ne!(Answer::from("5"), Answer::from(5u32));
+    }
+
+    #[test]
+    fn test_equality_with_stored() {
+        assert_eq!(Answer::from(55621u32), "55621");
+        assert_eq!(Answer::from(-1i64), "-1\n");
+        assert_ne!(Answer::from(55621u32), "55622");
+        assert_eq!(Answer::from("EZJR"), "EZJR");
+    }
+}
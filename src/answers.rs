@@ -0,0 +1,345 @@
+use crate::answer::Answer;
+use crate::solution::{solve_part1, solve_part2, Solution};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::Path;
+use toml_edit::{DocumentMut, InlineTable, Item, Table};
+
+// next to Cargo.toml, so it's found no matter where the runner is started from
+pub const ANSWERS_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/answers.toml");
+
+#[derive(Debug, PartialEq, Clone, Copy, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AnswerStatus {
+    Verified,
+    Unknown,
+    KnownWrong,
+}
+
+impl AnswerStatus {
+    // as written in answers.toml
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AnswerStatus::Verified => "verified",
+            AnswerStatus::Unknown => "unknown",
+            AnswerStatus::KnownWrong => "known-wrong",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct KnownAnswer {
+    pub answer: Option<String>,
+    pub status: AnswerStatus,
+}
+
+// how a solution's answer compares to answers.toml
+#[derive(Debug, PartialEq)]
+pub enum Check {
+    Pass,
+    Fail { expected: String },
+    KnownWrong,
+    Unknown,
+}
+
+impl Check {
+    // a verified answer that doesn't match, or one already rejected by adventofcode.com
+    pub fn failed(&self) -> bool {
+        matches!(self, Check::Fail { .. } | Check::KnownWrong)
+    }
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Check::Pass => write!(f, "pass"),
+            Check::Fail { expected } => write!(f, "FAIL, expected {expected}"),
+            Check::KnownWrong => write!(f, "known wrong"),
+            Check::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+// answers.toml, keyed by (year, day, part)
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers: HashMap<(u16, u8, u8), KnownAnswer>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DayEntry {
+    part1: Option<KnownAnswer>,
+    part2: Option<KnownAnswer>,
+}
+
+impl Answers {
+    pub fn load() -> Result<Answers, String> {
+        let text = fs::read_to_string(ANSWERS_PATH)
+            .map_err(|err| format!("Failed to read {ANSWERS_PATH}: {err}"))?;
+
+        Answers::parse(&text).map_err(|err| format!("Invalid {ANSWERS_PATH}: {err}"))
+    }
+
+    pub fn parse(text: &str) -> Result<Answers, String> {
+        // [2023.01] -> { "2023": { "01": { part1, part2 } } }
+        let years: HashMap<String, HashMap<String, DayEntry>> =
+            toml::from_str(text).map_err(|err| err.to_string())?;
+
+        let mut answers = HashMap::new();
+        for (year_key, days) in years {
+            let year: u16 = year_key
+                .parse()
+                .map_err(|_| format!("{year_key} is not a year"))?;
+
+            for (day_key, entry) in days {
+                let day: u8 = day_key
+                    .parse()
+                    .map_err(|_| format!("{year_key}.{day_key} is not a day"))?;
+
+                for (part, known) in [(1, entry.part1), (2, entry.part2)] {
+                    let Some(known) = known else { continue };
+
+                    if known.answer.is_none() && known.status != AnswerStatus::Unknown {
+                        return Err(format!(
+                            "{year_key}.{day_key} part{part} is {:?} but has no answer",
+                            known.status
+                        ));
+                    }
+
+                    answers.insert((year, day, part), known);
+                }
+            }
+        }
+
+        Ok(Answers { answers })
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: u8) -> Option<&KnownAnswer> {
+        self.answers.get(&(year, day, part))
+    }
+
+    pub fn check(&self, year: u16, day: u8, part: u8, answer: &Answer) -> Check {
+        let Some(known) = self.get(year, day, part) else {
+            return Check::Unknown;
+        };
+
+        match (known.status, known.answer.as_deref()) {
+            (AnswerStatus::Verified, Some(expected)) if answer == expected => Check::Pass,
+            (AnswerStatus::Verified, Some(expected)) => Check::Fail {
+                expected: expected.to_string(),
+            },
+            (AnswerStatus::KnownWrong, Some(wrong)) if answer == wrong => Check::KnownWrong,
+            // a different answer to a known wrong one might be right, we just don't know yet
+            _ => Check::Unknown,
+        }
+    }
+}
+
+// writes one part's entry back to answers.toml, keeping everything else (comments included) as is
+pub fn record_answer(
+    path: &Path,
+    year: u16,
+    day: u8,
+    part: u8,
+    known: &KnownAnswer,
+) -> Result<(), String> {
+    let text = fs::read_to_string(path)
+        .map_err(|err| format!("Failed to read {}: {err}", path.display()))?;
+    let mut document: DocumentMut = text
+        .parse()
+        .map_err(|err| format!("Invalid {}: {err}", path.display()))?;
+
+    // [2023.01] is a day table inside an implicit year table
+    let year_table = document
+        .entry(&year.to_string())
+        .or_insert_with(|| {
+            let mut table = Table::new();
+            table.set_implicit(true);
+            Item::Table(table)
+        })
+        .as_table_mut()
+        .ok_or_else(|| format!("{year} isn't a table in {}", path.display()))?;
+    let day_table = year_table
+        .entry(&format!("{day:02}"))
+        .or_insert_with(|| Item::Table(Table::new()))
+        .as_table_mut()
+        .ok_or_else(|| format!("{year}.{day:02} isn't a table in {}", path.display()))?;
+
+    let mut entry = InlineTable::new();
+    if let Some(answer) = &known.answer {
+        entry.insert("answer", answer.as_str().into());
+    }
+    entry.insert("status", known.status.as_str().into());
+    day_table.insert(&format!("part{part}"), toml_edit::value(entry));
+
+    fs::write(path, document.to_string())
+        .map_err(|err| format!("Failed to write {}: {err}", path.display()))
+}
+
+// used by each day's test_main, fails if an answer doesn't match a verified one or is known wrong
+pub fn assert_answers<S: Solution>() {
+    let answers = Answers::load().unwrap();
+
+    let solved: [(u8, Answer); 2] = [
+        (1, solve_part1::<S>().into()),
+        (2, solve_part2::<S>().into()),
+    ];
+
+    for (part, answer) in solved {
+        assert_answer(&answers, S::YEAR, S::DAY, part, &answer);
+    }
+}
+
+fn assert_answer(answers: &Answers, year: u16, day: u8, part: u8, answer: &Answer) {
+    let check = answers.check(year, day, part, answer);
+    if check.failed() {
+        panic!("{year} day {day:02} part {part}: got {answer}, {check}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::temp_dir::TempDir;
+
+    const EXAMPLE_ANSWERS: &str = r#"
+[2023.01]
+part1 = { answer = "142", status = "verified" }
+part2 = { answer = "281", status = "known-wrong" }
+
+[2022.23]
+part1 = { status = "unknown" }
+    "#;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(EXAMPLE_ANSWERS).unwrap();
+
+        assert_eq!(
+            answers.get(2023, 1, 1),
+            Some(&KnownAnswer {
+                answer: Some("142".to_string()),
+                status: AnswerStatus::Verified,
+            })
+        );
+        assert_eq!(
+            answers.get(2023, 1, 2).unwrap().status,
+            AnswerStatus::KnownWrong
+        );
+        assert_eq!(answers.get(2022, 23, 1).unwrap().answer, None);
+        assert_eq!(answers.get(2022, 23, 2), None);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(Answers::parse("[2023.01]\npart1 = { status = \"verified\" }").is_err());
+        assert!(
+            Answers::parse("[2023.01]\npart1 = { answer = \"1\", status = \"maybe\" }").is_err()
+        );
+        assert!(Answers::parse("[2023.first]\npart1 = { status = \"unknown\" }").is_err());
+        assert!(Answers::parse("[2023.01]\npart3 = { status = \"unknown\" }").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse(EXAMPLE_ANSWERS).unwrap();
+
+        assert_eq!(
+            answers.check(2023, 1, 1, &Answer::from(142u32)),
+            Check::Pass
+        );
+        assert_eq!(
+            answers.check(2023, 1, 1, &Answer::from(143u32)),
+            Check::Fail {
+                expected: "142".to_string()
+            }
+        );
+        assert_eq!(
+            answers.check(2023, 1, 2, &Answer::from(281u32)),
+            Check::KnownWrong
+        );
+        assert_eq!(
+            answers.check(2023, 1, 2, &Answer::from(280u32)),
+            Check::Unknown
+        );
+        assert_eq!(
+            answers.check(2022, 23, 1, &Answer::from(4114u32)),
+            Check::Unknown
+        );
+        assert_eq!(
+            answers.check(1999, 1, 1, &Answer::from(1u32)),
+            Check::Unknown
+        );
+    }
+
+    #[test]
+    fn test_assert_answer() {
+        let answers = Answers::parse(EXAMPLE_ANSWERS).unwrap();
+
+        assert_answer(&answers, 2023, 1, 1, &Answer::from(142u32));
+        assert_answer(&answers, 2023, 1, 2, &Answer::from(280u32));
+        assert!(!Check::Unknown.failed() && !Check::Pass.failed());
+    }
+
+    #[test]
+    #[should_panic(expected = "2023 day 01 part 2: got 281, known wrong")]
+    fn test_assert_known_wrong_answer() {
+        let answers = Answers::parse(EXAMPLE_ANSWERS).unwrap();
+
+        assert_answer(&answers, 2023, 1, 2, &Answer::from(281u32));
+    }
+
+    #[test]
+    #[should_panic(expected = "2023 day 01 part 1: got 143, FAIL, expected 142")]
+    fn test_assert_wrong_answer() {
+        let answers = Answers::parse(EXAMPLE_ANSWERS).unwrap();
+
+        assert_answer(&answers, 2023, 1, 1, &Answer::from(143u32));
+    }
+
+    #[test]
+    fn test_record_answer() {
+        let dir = TempDir::new("answers");
+        let path = dir.join("answers.toml");
+        fs::write(&path, format!("# comments are kept\n{EXAMPLE_ANSWERS}")).unwrap();
+
+        let verified = KnownAnswer {
+            answer: Some("4114".to_string()),
+            status: AnswerStatus::Verified,
+        };
+        record_answer(&path, 2022, 23, 1, &verified).unwrap();
+
+        // new day & year
+        let wrong = KnownAnswer {
+            answer: Some("12".to_string()),
+            status: AnswerStatus::KnownWrong,
+        };
+        record_answer(&path, 2021, 2, 2, &wrong).unwrap();
+
+        let text = fs::read_to_string(&path).unwrap();
+        assert!(text.starts_with("# comments are kept\n"));
+        assert!(text.contains("part1 = { answer = \"4114\", status = \"verified\" }"));
+
+        let answers = Answers::parse(&text).unwrap();
+        assert_eq!(answers.get(2022, 23, 1), Some(&verified));
+        assert_eq!(answers.get(2021, 2, 2), Some(&wrong));
+        assert_eq!(
+            answers.check(2023, 1, 1, &Answer::from(142u32)),
+            Check::Pass
+        );
+    }
+
+    #[test]
+    fn test_load() {
+        // every registered solution's year & day should have an entry
+        let answers = Answers::load().unwrap();
+
+        for (year, day) in crate::days::implemented_days() {
+            assert!(answers.get(year, day, 1).is_some(), "{year} day {day:02}");
+        }
+    }
+}
@@ -1,76 +1,218 @@
 // used to scaffold scripts for each day & and pull down the input
-
+// cargo run --bin 00_generate -- --year 2022 --day 4 --layout split
+// with no arguments, the year, day & layout are asked for
+
+use advent_of_code_2023::examples::extract_examples;
+use advent_of_code_2023::input::{InputSource, CURRENT_YEAR};
+use advent_of_code_2023::puzzle::{existing_puzzle_path, save_puzzle};
+use advent_of_code_2023::scaffold::{GenerateOptions, Layout, USAGE};
+use advent_of_code_2023::site::inputs::{Fetched, InputCache};
+use advent_of_code_2023::site::offline_from_env;
+use advent_of_code_2023::site::puzzle::fetch_puzzle;
 use std::io::Write;
+use std::path::Path;
+use std::process::ExitCode;
+use std::str::FromStr;
 use std::{fs, io};
 
-fn main() {
-    // ask user for day number to generate
-    let mut input = String::new();
-
-    print!("Enter day number: ");
-    io::stdout().flush().unwrap();
-    io::stdin().read_line(&mut input).unwrap();
-
-    let day_num: i32 = input.trim().parse().expect("Please enter a number!");
+fn main() -> ExitCode {
+    let options = match GenerateOptions::from_args(std::env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => match ask_for_options() {
+            Ok(options) => options,
+            Err(message) => {
+                eprintln!("{message}");
+                return ExitCode::from(2);
+            }
+        },
+        Err(message) => {
+            eprintln!("{message}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let mut scaffold = options.scaffold();
+    let saved_html = match &options.puzzle_html {
+        Some(puzzle_html) => match fs::read_to_string(puzzle_html) {
+            Ok(html) => Some(html),
+            Err(err) => {
+                eprintln!("Failed to read {}: {err}", puzzle_html.display());
+                return ExitCode::FAILURE;
+            }
+        },
+        None => None,
+    };
+    if let Some(html) = &saved_html {
+        if !options.refresh_puzzle {
+            let examples = extract_examples(html);
+            println!("Found {} example(s) in the puzzle page", examples.len());
+            scaffold = scaffold.with_examples(examples);
+        }
+    }
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let relative = |path: &Path| {
+        path.strip_prefix(root)
+            .unwrap_or(path)
+            .display()
+            .to_string()
+    };
+
+    let inputs = InputSource::resolve(None, None);
+    let input_cache = InputCache::from_env(inputs.clone(), options.session_file.as_deref());
+
+    // the saved page if there is one, otherwise the site's (unless offline)
+    let puzzle_html = || match &saved_html {
+        Some(html) => Ok(html.clone()),
+        None if offline_from_env() => Err("offline & no --puzzle-html given".to_string()),
+        None => fetch_puzzle(input_cache.client(), options.year, options.day)
+            .map_err(|err| err.to_string()),
+    };
+
+    if options.refresh_puzzle {
+        let Some(path) = existing_puzzle_path(root, options.year, options.day) else {
+            eprintln!(
+                "{} day {} hasn't been generated yet",
+                options.year, options.day
+            );
+            return ExitCode::FAILURE;
+        };
+
+        let saved = puzzle_html().and_then(|html| {
+            save_puzzle(&path, &html, options.year, options.day).map_err(|err| err.to_string())
+        });
+        return match saved {
+            Ok(()) => {
+                println!("wrote {}", relative(&path));
+                ExitCode::SUCCESS
+            }
+            Err(err) => {
+                eprintln!("Couldn't save the puzzle: {err}");
+                ExitCode::FAILURE
+            }
+        };
+    }
 
-    // generate paths for script & input files we will write
-    let script_path = format!("src/bin/{day_num:02}.rs");
-    let input_path = format!("inputs/{day_num:02}.txt");
+    if options.dry_run {
+        let plan = match scaffold.plan(root) {
+            Ok(plan) => plan,
+            Err(err) => {
+                eprintln!("{err}");
+                return ExitCode::FAILURE;
+            }
+        };
+
+        for (path, change) in plan {
+            println!("would {change} {}", relative(&path));
+        }
+        if saved_html.is_some() || !offline_from_env() {
+            println!("would create {}", scaffold.puzzle_path().display());
+        }
+        if !input_cache.is_cached(options.year, options.day) {
+            println!(
+                "would download {}",
+                relative(&inputs.path(options.year, options.day))
+            );
+        }
+
+        return ExitCode::SUCCESS;
+    }
 
-    // check if either file already exists
-    if fs::metadata(&script_path).is_ok() || fs::metadata(&input_path).is_ok() {
-        println!("Script or input already exists!");
-        return;
+    // write the day's files & register it
+    let written = match scaffold.create(root) {
+        Ok(written) => written,
+        Err(err) => {
+            eprintln!("Failed to generate day: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    for (path, _) in &written {
+        println!("wrote {}", relative(path));
     }
 
-    // load template & replace strings as needed
-    let script_code = fs::read_to_string("src/bin/00_template.rs")
-        .unwrap()
-        .replace("00", &format!("{day_num:02}"));
+    // the description's nice to have, so failing to get it is only a warning
+    let puzzle_path = root.join(scaffold.puzzle_path());
+    let saved = puzzle_html().and_then(|html| {
+        save_puzzle(&puzzle_path, &html, options.year, options.day).map_err(|err| err.to_string())
+    });
+    match saved {
+        Ok(()) => println!("wrote {}", relative(&puzzle_path)),
+        Err(err) => eprintln!("Couldn't save the puzzle: {err}"),
+    }
 
-    // write both our a & b parts
-    fs::write(&script_path, script_code).unwrap();
+    // download input from AoC website, unless it's already been downloaded
+    // AOC_OFFLINE=1 to only use previously downloaded inputs
+    let input_result = input_cache.get(options.year, options.day);
+    match &input_result {
+        Ok(Fetched::Downloaded(_)) => println!("Downloaded input"),
+        Ok(Fetched::Cached(_)) => println!("Using previously downloaded input"),
+        Err(err) => eprintln!("Couldn't get input: {err}"),
+    }
 
-    // download input from AoC website
-    let input_contents = download_input_from_aoc_api(day_num);
-    fs::write(input_path, input_contents).expect("Failed to write input file");
+    println!(
+        "To run: cargo run --bin aoc -- --year {} --day {}",
+        options.year, options.day
+    );
 
-    println!("Generated script & input files!");
-    println!("To run: cargo run --bin {day_num:02}a");
+    if let Some(editor) = &options.editor {
+        // "code -r" -> code, ["-r"]
+        let mut editor_parts = editor.split_whitespace();
+        let opened = editor_parts.next().is_some_and(|program| {
+            std::process::Command::new(program)
+                .args(editor_parts)
+                .arg(&written[0].0)
+                // don't wait around for the editor to be closed
+                .spawn()
+                .is_ok()
+        });
+        if !opened {
+            eprintln!("Couldn't open the new day with {editor}");
+        }
+    }
 
-    std::process::Command::new("idea")
-        .arg(script_path)
-        .spawn()
-        .expect("Failed to open script in IDE");
+    match input_result {
+        Ok(_) => ExitCode::SUCCESS,
+        Err(_) => ExitCode::FAILURE,
+    }
 }
 
-fn download_input_from_aoc_api(day_num: i32) -> String {
-    let input_url = format!("https://adventofcode.com/2023/day/{day_num}/input");
-
-    // load session token from env
-    let session_token = std::env::var("AOC_SESSION_TOKEN").expect("AOC_SESSION_TOKEN env not set");
-
-    let client = reqwest::blocking::Client::new();
+fn ask_for_options() -> Result<GenerateOptions, String> {
+    let year = prompt(
+        &format!("Enter year (default {CURRENT_YEAR}): "),
+        Some(CURRENT_YEAR),
+    )?;
+    let day: u8 = prompt("Enter day number: ", None)?;
+    if !(1..=25).contains(&day) {
+        return Err("Day must be between 1 and 25".to_string());
+    }
+    let layout = prompt(
+        "Layout, single or split (default single): ",
+        Some(Layout::Single),
+    )?;
+
+    Ok(GenerateOptions {
+        year,
+        day,
+        layout,
+        puzzle_html: None,
+        editor: None,
+        dry_run: false,
+        session_file: None,
+        refresh_puzzle: false,
+    })
+}
 
-    // set request headers (auth cookie)
-    let mut headers = reqwest::header::HeaderMap::new();
-    let cookie_value = format!("session={}", session_token);
-    headers.insert(
-        "Cookie",
-        reqwest::header::HeaderValue::from_str(&cookie_value).unwrap(),
-    );
+fn prompt<T: FromStr>(message: &str, default: Option<T>) -> Result<T, String> {
+    let mut input = String::new();
 
-    // Build the request with custom headers
-    let response = client
-        .get(input_url)
-        .headers(headers)
-        .send()
-        .expect("Failed to send request");
+    print!("{message}");
+    io::stdout().flush().map_err(|err| err.to_string())?;
+    io::stdin()
+        .read_line(&mut input)
+        .map_err(|err| err.to_string())?;
 
-    // Check if the request was successful
-    if !response.status().is_success() {
-        panic!("Failed to fetch the file: {}", response.status());
+    match (input.trim().parse(), default) {
+        (Ok(value), _) => Ok(value),
+        (Err(_), Some(default)) if input.trim().is_empty() => Ok(default),
+        (Err(_), _) => Err(format!("Invalid value: {}", input.trim())),
     }
-
-    response.text().expect("Failed to read response text")
 }
@@ -0,0 +1,142 @@
+use advent_of_code_2023::answers::{record_answer, Answers, Check, ANSWERS_PATH};
+use advent_of_code_2023::days::registry;
+use advent_of_code_2023::output::format_duration;
+use advent_of_code_2023::puzzle::{existing_puzzle_path, save_puzzle};
+use advent_of_code_2023::readme::{progress_markdown, update_readme, README_PATH};
+use advent_of_code_2023::runner::{results_table, run, total_time, Options, SolutionResult, USAGE};
+use advent_of_code_2023::site::puzzle::fetch_puzzle;
+use advent_of_code_2023::site::submit::{SubmitOutcome, Submitter};
+use std::path::Path;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let options = match Options::from_args(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{message}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let answers = match Answers::load() {
+        Ok(answers) => answers,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let results = match run(&options, &answers) {
+        Ok(results) => results,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    if results.is_empty() {
+        eprintln!("No solutions found for the selected year/day");
+        return ExitCode::FAILURE;
+    }
+
+    println!("{}", results_table(&results));
+    println!(
+        "\nTotal time: {} (mean of {} iteration(s))",
+        format_duration(total_time(&results)),
+        options.iterations
+    );
+
+    if options.submit {
+        return submit(&results[0], options.session_file.as_deref());
+    }
+
+    if options.update_readme {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let progress = progress_markdown(&registry(), &answers, &results, root);
+        match update_readme(Path::new(README_PATH), &progress) {
+            Ok(()) => println!("Updated the progress tables in readme.md"),
+            Err(message) => {
+                eprintln!("{message}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    // answers that don't match a verified one in answers.toml, or are known to be wrong
+    if results.iter().any(|result| result.failed()) {
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
+}
+
+// --submit, a single day & part
+fn submit(result: &SolutionResult, session_file: Option<&Path>) -> ExitCode {
+    let part_result = &result.parts[0];
+    let answer = &part_result.answer;
+
+    // no point asking about answers we already know about
+    match &part_result.check {
+        Check::Pass => {
+            println!("{answer} is already verified, not submitting");
+            return ExitCode::SUCCESS;
+        }
+        Check::Fail { expected } => {
+            eprintln!("{answer} doesn't match the verified answer {expected}, not submitting");
+            return ExitCode::FAILURE;
+        }
+        Check::KnownWrong => {
+            eprintln!("{answer} is already known to be wrong, not submitting");
+            return ExitCode::FAILURE;
+        }
+        Check::Unknown => {}
+    }
+
+    let submitter = Submitter::from_env(session_file);
+    let outcome = match submitter.submit(result.year, result.day, part_result.part, answer) {
+        Ok(outcome) => outcome,
+        Err(err) => {
+            eprintln!("Failed to submit: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    println!("\n{outcome}");
+
+    if let Some(known) = outcome.known_answer(answer) {
+        let recorded = record_answer(
+            Path::new(ANSWERS_PATH),
+            result.year,
+            result.day,
+            part_result.part,
+            &known,
+        );
+        match recorded {
+            Ok(()) => println!("Recorded as {} in answers.toml", known.status.as_str()),
+            Err(message) => eprintln!("{message}"),
+        }
+    }
+
+    // part 2's description only shows up once part 1 is solved
+    if outcome == SubmitOutcome::Correct && part_result.part == 1 {
+        refresh_puzzle(&submitter, result.year, result.day);
+    }
+
+    match outcome {
+        SubmitOutcome::Correct => ExitCode::SUCCESS,
+        _ => ExitCode::FAILURE,
+    }
+}
+
+fn refresh_puzzle(submitter: &Submitter, year: u16, day: u8) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let Some(path) = existing_puzzle_path(root, year, day) else {
+        return;
+    };
+
+    let saved = fetch_puzzle(submitter.client(), year, day)
+        .map_err(|err| err.to_string())
+        .and_then(|html| save_puzzle(&path, &html, year, day).map_err(|err| err.to_string()));
+    match saved {
+        Ok(()) => println!("Saved part 2's description to {}", path.display()),
+        Err(err) => eprintln!("Couldn't refresh the puzzle: {err}"),
+    }
+}
@@ -0,0 +1,70 @@
+use crate::solution::DynSolution;
+
+pub mod y2021;
+pub mod y2022;
+pub mod y2023;
+
+// scaffolds for new days, copied by 00_generate (compiled so they don't rot)
+// template.rs solves both parts in one file, template_split/ has a file per part
+#[allow(dead_code)]
+mod template;
+#[allow(dead_code)]
+mod template_split;
+
+// every implemented day, oldest first
+pub fn registry() -> Vec<&'static dyn DynSolution> {
+    vec![
+        &y2021::day01::Day01,
+        &y2022::day01::Day01,
+        &y2022::day04::Day04,
+        &y2022::day23::Day23,
+        &y2022::day23_performance::Day23Performance,
+        &y2023::day01::Day01,
+        &y2023::day02::Day02,
+        &y2023::day03::Day03,
+        &y2023::day04::Day04,
+        &y2023::day05::Day05,
+        &y2023::day06::Day06,
+        &y2023::day07::Day07,
+        // new days are added above this line by 00_generate
+    ]
+}
+
+// (year, day) pairs with at least one solution
+pub fn implemented_days() -> Vec<(u16, u8)> {
+    let mut days: Vec<(u16, u8)> = registry()
+        .iter()
+        .map(|solution| (solution.year(), solution.day()))
+        .collect();
+
+    // performance variants share a year & day with the original
+    days.dedup();
+
+    days
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_implemented_days() {
+        let days = implemented_days();
+
+        assert_eq!(days.first(), Some(&(2021, 1)));
+        assert!(days.contains(&(2022, 23)));
+        assert!(days.contains(&(2023, 7)));
+
+        // 2022 day 23 is only listed once, even though it has a performance variant
+        assert_eq!(days.iter().filter(|day| **day == (2022, 23)).count(), 1);
+    }
+
+    #[test]
+    fn test_registry_is_sorted() {
+        let registry = registry();
+
+        assert!(registry
+            .windows(2)
+            .all(|pair| (pair[0].year(), pair[0].day()) <= (pair[1].year(), pair[1].day())));
+    }
+}
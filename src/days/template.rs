@@ -0,0 +1,47 @@
+use crate::parse::{numbered_lines, ParseError};
+use crate::solution::Solution;
+
+pub struct Day00;
+
+impl Solution for Day00 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 0;
+
+    type Input = Vec<i32>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        numbered_lines(input)
+            .map(|line| line.parse_with(|parser, text| parser.number::<i32>(text))) // parse each value
+            .collect()
+    }
+
+    fn part1(items: &Self::Input) -> Self::Part1 {
+        items.iter().for_each(|x| println!("{}", x));
+
+        0
+    }
+
+    fn part2(_items: &Self::Input) -> Self::Part2 {
+        0
+    }
+}
+
+// #[cfg(test)]
+// mod tests {
+//     use super::*;
+//     use crate::answers::assert_answers;
+//
+//     #[test]
+//     fn test_main() {
+//         // checks against answers.toml
+//         assert_answers::<Day00>();
+//     }
+//
+//     #[test]
+//     fn test_example_input() {
+//         let example_input = "???";
+//         assert_eq!(Day00::part1(&Day00::parse(example_input).unwrap()), 0);
+//     }
+// }
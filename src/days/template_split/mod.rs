@@ -0,0 +1,47 @@
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+mod part1;
+mod part2;
+
+// part 1 & 2 are solved separately in part1.rs & part2.rs, so both parse the raw input themselves
+pub struct Day00;
+
+impl Solution for Day00 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 0;
+
+    type Input = String;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1::challenge(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2::challenge(input)
+    }
+}
+
+// #[cfg(test)]
+// mod tests {
+//     use super::*;
+//     use crate::answers::assert_answers;
+//
+//     #[test]
+//     fn test_main() {
+//         // checks against answers.toml
+//         assert_answers::<Day00>();
+//     }
+//
+//     #[test]
+//     fn test_example_input() {
+//         let example_input = "???";
+//         assert_eq!(Day00::part1(&example_input.to_string()), 0);
+//     }
+// }
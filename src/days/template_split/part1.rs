@@ -0,0 +1,7 @@
+use crate::input::lines;
+
+pub fn challenge(input: &str) -> u32 {
+    lines(input).for_each(|line| println!("{}", line));
+
+    0
+}
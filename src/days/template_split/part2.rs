@@ -0,0 +1,3 @@
+pub fn challenge(_input: &str) -> u32 {
+    0
+}
@@ -0,0 +1,64 @@
+use crate::parse::{numbered_lines, ParseError};
+use crate::solution::Solution;
+
+mod part1;
+mod part2;
+
+// part 1 & 2 are solved separately in part1.rs & part2.rs, both from the parsed depths
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 1;
+
+    // sonar depths, in the order they were measured
+    type Input = Vec<i32>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        numbered_lines(input)
+            .map(|line| line.parse_with(|parser, text| parser.number(text)))
+            .collect()
+    }
+
+    fn part1(depths: &Self::Input) -> Self::Part1 {
+        part1::challenge(depths)
+    }
+
+    fn part2(depths: &Self::Input) -> Self::Part2 {
+        part2::challenge(depths)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::assert_answers;
+
+    #[test]
+    fn test_main() {
+        assert_answers::<Day01>();
+    }
+
+    #[test]
+    fn test_parse_invalid_depth() {
+        let err = Day01::parse("199\n200\n2o8\n").unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 1: expected a number, found \"2o8\""
+        );
+    }
+
+    #[test]
+    fn test_too_few_depths() {
+        let depths = Day01::parse("").unwrap();
+        assert_eq!(Day01::part1(&depths), 0);
+        assert_eq!(Day01::part2(&depths), 0);
+
+        let depths = Day01::parse("199\n200\n").unwrap();
+        assert_eq!(Day01::part1(&depths), 1);
+        assert_eq!(Day01::part2(&depths), 0);
+    }
+}
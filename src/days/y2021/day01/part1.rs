@@ -0,0 +1,23 @@
+pub fn challenge(depths: &[i32]) -> u32 {
+    let mut num_depths_increases = 0;
+
+    // compare each depth with the one before it, nothing to compare the first depth with
+    for pair in depths.windows(2) {
+        if pair[1] > pair[0] {
+            num_depths_increases += 1;
+        }
+    }
+
+    num_depths_increases
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_input() {
+        let example_input = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+        assert_eq!(challenge(&example_input), 7);
+    }
+}
@@ -0,0 +1,29 @@
+pub fn challenge(depths: &[i32]) -> u32 {
+    let mut num_depths_increases = 0;
+
+    // each sliding window "a", "b", ... is 3 depths long, so stops short of the end
+    let window_depths: Vec<i32> = depths
+        .windows(3)
+        .map(|window| window.iter().sum())
+        .collect();
+
+    // compare each window with the one before it
+    for pair in window_depths.windows(2) {
+        if pair[1] > pair[0] {
+            num_depths_increases += 1;
+        }
+    }
+
+    num_depths_increases
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_input() {
+        let example_input = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+        assert_eq!(challenge(&example_input), 5);
+    }
+}
@@ -0,0 +1,77 @@
+use crate::parse::{records, ParseError};
+use crate::solution::Solution;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 1;
+
+    // total cals carried by each elf, largest to smallest
+    type Input = Vec<u32>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut elf_calories: Vec<u32> = records(input) // groups of lines
+            .iter()
+            .map(|elf|
+                // calculate the sum of each item in the group
+                elf.lines()
+                    .map(|line| line.parse_with(|parser, text| parser.number::<u32>(text))) // parse as numbers
+                    .sum::<Result<u32, ParseError>>())
+            .collect::<Result<Vec<u32>, ParseError>>()?;
+        if elf_calories.is_empty() {
+            return Err(ParseError::end_of_input(input, "an elf's calories"));
+        }
+
+        // sort elfs by total cals (largest to smallest)
+        elf_calories.sort();
+        elf_calories.reverse();
+
+        Ok(elf_calories)
+    }
+
+    fn part1(elf_calories: &Self::Input) -> Self::Part1 {
+        // total cals of largest elf
+        elf_calories.first().copied().unwrap_or_default()
+    }
+
+    fn part2(elf_calories: &Self::Input) -> Self::Part2 {
+        // take the largest 3 elfs & sum their total cals
+        elf_calories.iter().take(3).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::assert_answers;
+
+    #[test]
+    fn test_main() {
+        assert_answers::<Day01>();
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Day01::parse("\n\n").unwrap_err().to_string(),
+            "line 3, column 1: expected an elf's calories, found the end of the input"
+        );
+
+        let err = Day01::parse("1000\n2000\n\n4k00\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 1: expected a number, found \"4k00\""
+        );
+    }
+
+    #[test]
+    fn test_example_input() {
+        let elf_calories = Day01::parse("1000\n2000\n3000\n\n4000\n\n5000\n6000\n").unwrap();
+
+        assert_eq!(Day01::part1(&elf_calories), 11000);
+        assert_eq!(Day01::part2(&elf_calories), 21000);
+    }
+}
@@ -0,0 +1,146 @@
+use crate::interval::Interval;
+use crate::parse::{parse_lines, LineParser, ParseError};
+use crate::solution::Solution;
+use std::str::FromStr;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 4;
+
+    type Input = Vec<AssignmentPair>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // parse input into AssignmentPairs
+        parse_lines(input)
+    }
+
+    fn part1(assignment_pairs: &Self::Input) -> Self::Part1 {
+        // find which of these "fully contain" the other
+        assignment_pairs
+            .iter()
+            .filter(|ap| ap.fully_contains())
+            .count()
+    }
+
+    fn part2(assignment_pairs: &Self::Input) -> Self::Part2 {
+        assignment_pairs.iter().filter(|ap| ap.overlaps()).count()
+    }
+}
+
+#[derive(Debug)]
+pub struct AssignmentPair {
+    a: Interval,
+    b: Interval,
+}
+
+impl FromStr for AssignmentPair {
+    type Err = ParseError;
+
+    fn from_str(assignment_pair_str: &str) -> Result<Self, ParseError> {
+        // "2-4,6-8"
+        let parser = LineParser::new(assignment_pair_str);
+        let (a_str, b_str) = parser.split_once(assignment_pair_str, ",")?;
+        let assignment = |text| -> Result<Interval, ParseError> {
+            let (start, end) = parser.split_once(text, "-")?;
+            let (start, end) = (parser.number(start)?, parser.number(end)?);
+            if start > end {
+                return Err(parser.error(text, "a start before the end"));
+            }
+
+            Ok(Interval::new(start, end))
+        };
+
+        Ok(AssignmentPair {
+            a: assignment(a_str)?,
+            b: assignment(b_str)?,
+        })
+    }
+}
+
+impl AssignmentPair {
+    pub fn fully_contains(&self) -> bool {
+        // either way around
+        self.a.contains_interval(&self.b) || self.b.contains_interval(&self.a)
+    }
+
+    pub fn overlaps(&self) -> bool {
+        self.a.overlaps(&self.b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::assert_answers;
+
+    #[test]
+    fn test_main() {
+        assert_answers::<Day04>();
+    }
+
+    #[test]
+    fn test_ap_from_str() {
+        let ap = AssignmentPair::from_str("1-2,3-4").unwrap();
+
+        assert_eq!(ap.a, Interval::new(1, 2));
+        assert_eq!(ap.b, Interval::new(3, 4));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            AssignmentPair::from_str("1-2,3").unwrap_err().to_string(),
+            "column 5: expected \"-\", found \"3\""
+        );
+
+        assert_eq!(
+            AssignmentPair::from_str("4-2,3-4").unwrap_err().to_string(),
+            "column 1: expected a start before the end, found \"4-2\""
+        );
+
+        let err = parse_lines::<AssignmentPair>("1-2,3-4\n1-2,3-x").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 7: expected a number, found \"x\""
+        );
+    }
+
+    #[test]
+    fn test_pair_fully_contains_other_pair() {
+        assert!(!AssignmentPair::from_str("1-2,3-4")
+            .unwrap()
+            .fully_contains());
+        assert!(!AssignmentPair::from_str("3-4,1-2")
+            .unwrap()
+            .fully_contains());
+        assert!(AssignmentPair::from_str("1-4,2-3")
+            .unwrap()
+            .fully_contains());
+        assert!(AssignmentPair::from_str("2-3,1-4")
+            .unwrap()
+            .fully_contains());
+    }
+
+    #[test]
+    fn test_overlap() {
+        // provided example cases
+        assert!(AssignmentPair::from_str("5-7,7-9").unwrap().overlaps());
+        assert!(AssignmentPair::from_str("2-8,3-7").unwrap().overlaps());
+        assert!(AssignmentPair::from_str("6-6,4-6").unwrap().overlaps());
+        assert!(AssignmentPair::from_str("2-6,4-8").unwrap().overlaps());
+
+        // custom example cases
+        assert!(!AssignmentPair::from_str("1-2,3-4").unwrap().overlaps());
+        assert!(!AssignmentPair::from_str("3-4,1-2").unwrap().overlaps());
+
+        assert!(AssignmentPair::from_str("1-4,2-3").unwrap().overlaps());
+        assert!(AssignmentPair::from_str("2-3,1-4").unwrap().overlaps());
+
+        assert!(AssignmentPair::from_str("1-3,2-4").unwrap().overlaps());
+        assert!(AssignmentPair::from_str("2-4,1-3").unwrap().overlaps());
+    }
+}
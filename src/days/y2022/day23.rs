@@ -1,63 +1,58 @@
+use crate::geometry::{Direction4, Direction8, Point2};
+use crate::input::lines;
+use crate::parse::ParseError;
+use crate::solution::Solution;
 use std::collections::{HashMap, HashSet, VecDeque};
-use std::fs;
 
-fn main() {
-    let input = fs::read_to_string("inputs/2022_23.txt").expect("Failed to read input file");
+pub struct Day23;
 
-    let answer1 = challenge_part1(&input);
-    println!("2022, Day 23, Part 1: {}", answer1);
-    assert_eq!(answer1, 4114);
+impl Solution for Day23 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 23;
 
-    let answer2 = challenge_part2(&input);
-    println!("2022, Day 23, Part 2: {}", answer2);
-    assert_eq!(answer2, 970);
-}
+    type Input = Game;
+    type Part1 = usize;
+    type Part2 = usize;
 
-fn challenge_part1(input: &str) -> usize {
-    let mut game = Game::from_str(input);
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Game::from_str(input))
+    }
 
-    game.tick_until(10);
+    fn part1(game: &Self::Input) -> Self::Part1 {
+        let mut game = game.clone();
 
-    game.num_empty_ground_tiles()
-}
+        game.tick_until(10);
+
+        game.num_empty_ground_tiles()
+    }
 
-fn challenge_part2(input: &str) -> usize {
-    let mut game = Game::from_str(input);
+    fn part2(game: &Self::Input) -> Self::Part2 {
+        let mut game = game.clone();
 
-    game.tick_until_complete();
+        game.tick_until_complete();
 
-    // num_ticks = last tick where there was movement
-    // we want the FIRST tick there was NO movement, add one
-    game.num_ticks + 1
+        // num_ticks = last tick where there was movement
+        // we want the FIRST tick there was NO movement, add one
+        game.num_ticks + 1
+    }
 }
 
 #[derive(Clone)]
-enum Direction {
-    North,
-    East,
-    South,
-    West,
-}
-
-struct Game {
-    elf_positions: HashSet<ElfPosition>,
-    move_ordering: VecDeque<Direction>,
+pub struct Game {
+    elf_positions: HashSet<Point2>,
+    move_ordering: VecDeque<Direction4>,
     is_complete: bool,
     num_ticks: usize,
 }
 
 impl Game {
     fn from_str(input: &str) -> Game {
-        let mut elf_positions: HashSet<ElfPosition> = HashSet::new();
+        let mut elf_positions: HashSet<Point2> = HashSet::new();
 
-        let lines = input.split('\n').filter(|x| !x.is_empty());
-        for (y, line) in lines.enumerate() {
+        for (y, line) in lines(input).enumerate() {
             for (x, c) in line.chars().enumerate() {
                 if c == '#' {
-                    elf_positions.insert(ElfPosition {
-                        x: x as i32,
-                        y: y as i32,
-                    });
+                    elf_positions.insert(Point2::new(x as i32, y as i32));
                 }
             }
         }
@@ -66,30 +61,29 @@ impl Game {
             elf_positions,
             // default move ordering
             move_ordering: VecDeque::from(vec![
-                Direction::North,
-                Direction::South,
-                Direction::West,
-                Direction::East,
+                Direction4::North,
+                Direction4::South,
+                Direction4::West,
+                Direction4::East,
             ]),
             is_complete: false,
             num_ticks: 0,
         }
     }
-    fn get_next_positions(&self) -> Option<HashSet<ElfPosition>> {
+    fn get_next_positions(&self) -> Option<HashSet<Point2>> {
         // room for optimisation here, takes ~4ms per tick in debug mode
 
         // for each position, compute a proposed next position for this game state
-        let proposed_moves: Vec<(&ElfPosition, ElfPosition)> = self
+        let proposed_moves: Vec<(&Point2, Point2)> = self
             .elf_positions
             .iter()
-            .map(|elf_position| (elf_position, elf_position.proposed_next_position(self)))
+            .map(|elf_position| (elf_position, proposed_next_position(*elf_position, self)))
             .collect();
 
         // iterate proposed moves, count proposed moves per destination
         // and check if we want to move at all
         let mut has_moves = false;
-        let mut num_elfs_wanting_to_move_into_position: HashMap<ElfPosition, usize> =
-            HashMap::new();
+        let mut num_elfs_wanting_to_move_into_position: HashMap<Point2, usize> = HashMap::new();
 
         for (current_position, proposed_position) in proposed_moves.iter() {
             // if we want to move, the game isn't over - take note of this
@@ -99,7 +93,7 @@ impl Game {
 
             // keep track of how many elfs want to move into this position
             num_elfs_wanting_to_move_into_position
-                .entry(proposed_position.clone())
+                .entry(*proposed_position)
                 .and_modify(|x| *x += 1)
                 .or_insert(1);
         }
@@ -110,7 +104,7 @@ impl Game {
         }
 
         // iterate proposed moves, move into proposed solution if no other elfs want to move there
-        let mut next_positions: HashSet<ElfPosition> = HashSet::new();
+        let mut next_positions: HashSet<Point2> = HashSet::new();
         for (current_position, proposed_position) in proposed_moves {
             // keep track of how many elfs want to move into this position
             if *num_elfs_wanting_to_move_into_position
@@ -118,10 +112,10 @@ impl Game {
                 .unwrap()
                 == 1
             {
-                next_positions.insert(proposed_position.clone());
+                next_positions.insert(proposed_position);
             } else {
                 // someone else wants to move here.. stay where we are
-                next_positions.insert(current_position.clone());
+                next_positions.insert(*current_position);
             }
         }
 
@@ -147,7 +141,8 @@ impl Game {
     }
 
     fn tick_until(&mut self, num_ticks: usize) {
-        while self.num_ticks < num_ticks {
+        // once nobody moves the rest of the ticks wouldn't change anything
+        while self.num_ticks < num_ticks && !self.is_complete {
             self.tick();
         }
     }
@@ -159,6 +154,11 @@ impl Game {
     }
 
     fn num_empty_ground_tiles(&self) -> usize {
+        // no elves covers no ground
+        if self.elf_positions.is_empty() {
+            return 0;
+        }
+
         let min_x = self.elf_positions.iter().map(|p| p.x).min().unwrap();
         let max_x = self.elf_positions.iter().map(|p| p.x).max().unwrap();
         let min_y = self.elf_positions.iter().map(|p| p.y).min().unwrap();
@@ -167,7 +167,7 @@ impl Game {
         let mut num_empty_ground_tiles = 0;
         for y in min_y..=max_y {
             for x in min_x..=max_x {
-                if !self.elf_positions.contains(&ElfPosition { x, y }) {
+                if !self.elf_positions.contains(&Point2::new(x, y)) {
                     num_empty_ground_tiles += 1;
                 }
             }
@@ -181,7 +181,7 @@ impl Game {
 
         for y in 0..=(height - 1) {
             for x in 0..=(width - 1) {
-                output_str += if self.elf_positions.contains(&ElfPosition { x, y }) {
+                output_str += if self.elf_positions.contains(&Point2::new(x, y)) {
                     "#"
                 } else {
                     "."
@@ -194,91 +194,29 @@ impl Game {
     }
 }
 
-#[derive(Hash, PartialEq, Eq, Clone, Debug)]
-struct ElfPosition {
-    x: i32,
-    y: i32,
-}
-
-impl ElfPosition {
-    fn proposed_next_position(&self, game: &Game) -> ElfPosition {
-        let current_elf_positions = &game.elf_positions;
-        let n = current_elf_positions.contains(&self.north());
-        let ne = current_elf_positions.contains(&self.north().east());
-        let e = current_elf_positions.contains(&self.east());
-        let se = current_elf_positions.contains(&self.south().east());
-        let s = current_elf_positions.contains(&self.south());
-        let sw = current_elf_positions.contains(&self.south().west());
-        let w = current_elf_positions.contains(&self.west());
-        let nw = current_elf_positions.contains(&self.north().west());
-
-        if !n && !ne && !e && !se && !s && !sw && !w && !nw {
-            // If no other Elves are in one of those eight positions,
-            // the Elf does not do anything during this round.
-            // propose we stay here
-            return self.clone();
-        }
-
-        for move_direction in &game.move_ordering {
-            match move_direction {
-                Direction::North => {
-                    // If there is no Elf in the N, NE, or NW adjacent positions, the Elf proposes moving north one step.
-                    if !n && !ne && !nw {
-                        return self.north();
-                    }
-                }
-                Direction::East => {
-                    // If there is no Elf in the S, SE, or SW adjacent positions, the Elf proposes moving south one step.
-                    if !e && !ne && !se {
-                        return self.east();
-                    }
-                }
-                Direction::South => {
-                    // If there is no Elf in the W, NW, or SW adjacent positions, the Elf proposes moving west one step.
-                    if !s && !se && !sw {
-                        return self.south();
-                    }
-                }
-                Direction::West => {
-                    // If there is no Elf in the E, NE, or SE adjacent positions, the Elf proposes moving east one step.
-                    if !w && !nw && !sw {
-                        return self.west();
-                    }
-                }
-            }
-        }
-
-        // no where to move, stay here
-        self.clone()
-    }
-
-    fn north(&self) -> ElfPosition {
-        ElfPosition {
-            x: self.x,
-            y: self.y - 1,
-        }
-    }
+fn proposed_next_position(elf: Point2, game: &Game) -> Point2 {
+    // clockwise from north, in Direction8::ALL order
+    let occupied = Direction8::ALL.map(|direction| game.elf_positions.contains(&(elf + direction)));
 
-    fn east(&self) -> ElfPosition {
-        ElfPosition {
-            x: self.x + 1,
-            y: self.y,
-        }
+    if !occupied.contains(&true) {
+        // If no other Elves are in one of those eight positions,
+        // the Elf does not do anything during this round.
+        // propose we stay here
+        return elf;
     }
 
-    fn south(&self) -> ElfPosition {
-        ElfPosition {
-            x: self.x,
-            y: self.y + 1,
+    for move_direction in &game.move_ordering {
+        // If there is no Elf in the three adjacent positions on that side (i.e. N, NE, or NW),
+        // the Elf proposes moving one step that way.
+        let direction = Direction8::from(*move_direction);
+        let side = [direction.rotate_ccw(), direction, direction.rotate_cw()];
+        if side.iter().all(|looking| !occupied[*looking as usize]) {
+            return elf + *move_direction;
         }
     }
 
-    fn west(&self) -> ElfPosition {
-        ElfPosition {
-            x: self.x - 1,
-            y: self.y,
-        }
-    }
+    // no where to move, stay here
+    elf
 }
 
 #[cfg(test)]
@@ -288,16 +226,32 @@ mod tests {
     // skipping main, as it takes ~10s to run in debug - _performance version runs and is faster
     // #[test]
     // fn test_main() {
-    //     main();
+    //     crate::answers::assert_answers::<Day23>();
     // }
 
+    #[test]
+    fn test_no_elves() {
+        let game = Day23::parse("...\n...\n").unwrap();
+
+        assert_eq!(Day23::part1(&game), 0);
+        assert_eq!(Day23::part2(&game), 1);
+    }
+
+    #[test]
+    fn test_already_settled() {
+        let game = Day23::parse(".#.\n").unwrap();
+
+        assert_eq!(Day23::part1(&game), 0);
+        assert_eq!(Day23::part2(&game), 1);
+    }
+
     #[test]
     fn test_elf_positions() {
-        let pos1 = ElfPosition { x: 0, y: 0 };
+        let pos1 = Point2::ORIGIN;
 
-        let moved = pos1.north().east().east().south();
-        assert_eq!(moved.x, 2);
-        assert_eq!(moved.y, 0);
+        let moved =
+            pos1 + Direction4::North + Direction4::East + Direction4::East + Direction4::South;
+        assert_eq!(moved, Point2::new(2, 0));
     }
 
     #[test]
@@ -587,6 +541,6 @@ mod tests {
 .............."
             .trim();
 
-        assert_eq!(challenge_part2(example_input), 20);
+        assert_eq!(Day23::part2(&Day23::parse(example_input).unwrap()), 20);
     }
 }
@@ -0,0 +1,4 @@
+pub mod day01;
+pub mod day04;
+pub mod day23;
+pub mod day23_performance;
@@ -0,0 +1,63 @@
+use crate::parse::{numbered_lines, ParseError};
+use crate::solution::Solution;
+
+mod part1;
+mod part2;
+
+// part 1 & 2 are solved separately in part1.rs & part2.rs, both from the checked lines
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+
+    // every line has a digit, spelled out or not, so part 2 always finds a calibration value
+    type Input = Vec<String>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        numbered_lines(input)
+            .map(|line| {
+                line.parse_with(|parser, text| match part2::decode_calibration_value(text) {
+                    Some(_) => Ok(text.to_string()),
+                    None => Err(parser.error(text, "a digit or a spelled out digit")),
+                })
+            })
+            .collect()
+    }
+
+    fn part1(lines: &Self::Input) -> Self::Part1 {
+        part1::challenge(lines)
+    }
+
+    fn part2(lines: &Self::Input) -> Self::Part2 {
+        part2::challenge(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::assert_answers;
+
+    #[test]
+    fn test_main() {
+        assert_answers::<Day01>();
+    }
+
+    #[test]
+    fn test_parse_line_without_digits() {
+        let err = Day01::parse("1abc2\r\npqrstuvwx\r\n").unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected a digit or a spelled out digit, found \"pqrstuvwx\""
+        );
+
+        // only spelled out, part 1 has nothing to read but part 2 does
+        let lines = Day01::parse("eightwothree\n7pqrstsixteen\n").unwrap();
+        assert_eq!(Day01::part1(&lines), 77);
+        assert_eq!(Day01::part2(&lines), 83 + 76);
+    }
+}
@@ -0,0 +1,56 @@
+pub fn challenge(lines: &[String]) -> u32 {
+    lines
+        .iter()
+        // "decode" the magic number from each line, lines with only spelled out digits are
+        // part 2's, there's nothing there for part 1
+        .filter_map(|line| decode_calibration_value(line))
+        .sum()
+}
+
+fn decode_calibration_value(line: &str) -> Option<u32> {
+    // take the first and last number, and put them together
+    // i.e "pqr3st1u8vwx" -> 38
+
+    let number_chars: Vec<u32> = line
+        .chars() // split into char iterator
+        .filter(|char| char.is_numeric()) // filter out non-numeric chars
+        .map(|digit_char| digit_char.to_digit(10).unwrap()) // parse digit chars '1' -> 1
+        .collect();
+
+    // i.e left with [3, 1, 8]. First & last are 3 & 8
+    let first = number_chars.first()?;
+    let last = number_chars.last()?;
+
+    // treat first as the tens place, and last as the ones place
+    // i.e. 3 * 10 + 8 = 38
+    Some((first * 10) + last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_input() {
+        let example_input = "
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+        "
+        .trim();
+        let lines: Vec<String> = example_input.lines().map(String::from).collect();
+
+        assert_eq!(challenge(&lines), 142);
+    }
+
+    #[test]
+    fn test_decode_calibration_value() {
+        assert_eq!(decode_calibration_value("1abc2"), Some(12));
+        assert_eq!(decode_calibration_value("pqr3stu8vwx"), Some(38));
+        assert_eq!(decode_calibration_value("pqr3st1u8vwx"), Some(38));
+        assert_eq!(decode_calibration_value("a1b2c3d4e5f"), Some(15));
+        assert_eq!(decode_calibration_value("treb7uchet"), Some(77));
+        assert_eq!(decode_calibration_value("eightwothree"), None);
+    }
+}
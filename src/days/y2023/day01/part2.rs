@@ -1,23 +1,13 @@
-use std::fs;
-
-fn main() {
-    let input_text = fs::read_to_string("inputs/01.txt").expect("Failed to read input file");
-
-    let answer = challenge(&input_text);
-
-    println!("Day 01, Part 2: {}", answer);
-    assert_eq!(answer, 53592);
-}
-
-fn challenge(input: &str) -> u32 {
-    input
-        .split('\n') // split by new line
-        .filter(|x| !x.is_empty()) // drop empty lines
-        .map(decode_calibration_value) // "decode" the magic number from each line
+pub fn challenge(lines: &[String]) -> u32 {
+    lines
+        .iter()
+        // "decode" the magic number from each line, parsing made sure every line has one
+        .filter_map(|line| decode_calibration_value(line))
         .sum()
 }
 
-fn decode_calibration_value(line: &str) -> u32 {
+// None when there's no digit on the line, spelled out or not
+pub fn decode_calibration_value(line: &str) -> Option<u32> {
     // find first number word moving forwards
     // find  last number word moving backwards
     let mut first_num = None;
@@ -26,7 +16,7 @@ fn decode_calibration_value(line: &str) -> u32 {
     // move forward through the string one character at a time until we find the first number
     // abconetwonez
     // ...^__       move cursor forward 3 until we match "one"
-    for cursor in 0..line.len() {
+    for (cursor, _) in line.char_indices() {
         // abconetwonez
         //  bconetwonez
         //   conetwonez
@@ -38,12 +28,12 @@ fn decode_calibration_value(line: &str) -> u32 {
             break;
         }
     }
-    let first_num = first_num.expect("No first number found");
+    let first_num = first_num?;
 
     // move backward through the string one character at a time until we find the last number
     // abconetwonez
     //         ^__.      move cursor backwards 4 until we match "one"
-    for cursor in (0..line.len()).rev() {
+    for (cursor, _) in line.char_indices().rev() {
         //    z
         //   ez
         //  nez
@@ -55,11 +45,11 @@ fn decode_calibration_value(line: &str) -> u32 {
             break;
         }
     }
-    let last_num = last_num.expect("No last number found");
+    let last_num = last_num?;
 
     // treat first as the tens place, and last as the ones place
     // i.e. 3 * 10 + 8 = 38
-    (first_num * 10) + last_num
+    Some((first_num * 10) + last_num)
 }
 
 // finds the first number (either string or digit) in a string
@@ -69,7 +59,7 @@ fn num_from_start_of_str(input: &str) -> Option<u32> {
     // "2qwer" -> 2
 
     // check if the first character is already a digit (0-9) and return if so
-    if let Some(digit) = input.chars().next().unwrap().to_digit(10) {
+    if let Some(digit) = input.chars().next()?.to_digit(10) {
         return Some(digit);
     }
 
@@ -100,11 +90,6 @@ fn num_from_start_of_str(input: &str) -> Option<u32> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_main() {
-        main();
-    }
-
     #[test]
     fn test_example_input() {
         let example_input = "
@@ -117,8 +102,9 @@ zoneight234
 7pqrstsixteen
         "
         .trim();
+        let lines: Vec<String> = example_input.lines().map(String::from).collect();
 
-        assert_eq!(challenge(example_input), 281);
+        assert_eq!(challenge(&lines), 281);
     }
 
     #[test]
@@ -138,24 +124,26 @@ zoneight234
 
     #[test]
     fn test_decode_int_calibration_value() {
-        assert_eq!(decode_calibration_value("1abc2"), 12);
-        assert_eq!(decode_calibration_value("pqr3stu8vwx"), 38);
-        assert_eq!(decode_calibration_value("a1b2c3d4e5f"), 15);
-        assert_eq!(decode_calibration_value("treb7uchet"), 77);
+        assert_eq!(decode_calibration_value("1abc2"), Some(12));
+        assert_eq!(decode_calibration_value("pqr3stu8vwx"), Some(38));
+        assert_eq!(decode_calibration_value("a1b2c3d4e5f"), Some(15));
+        assert_eq!(decode_calibration_value("treb7uchet"), Some(77));
     }
 
     #[test]
     fn test_decode_str_calibration_value() {
         // provided tests
-        assert_eq!(decode_calibration_value("two1nine"), 29);
-        assert_eq!(decode_calibration_value("eightwothree"), 83);
-        assert_eq!(decode_calibration_value("abcone2threexyz"), 13);
-        assert_eq!(decode_calibration_value("xtwone3four"), 24);
-        assert_eq!(decode_calibration_value("4nineeightseven2"), 42);
-        assert_eq!(decode_calibration_value("zoneight234"), 14);
-        assert_eq!(decode_calibration_value("7pqrstsixteen"), 76);
+        assert_eq!(decode_calibration_value("two1nine"), Some(29));
+        assert_eq!(decode_calibration_value("eightwothree"), Some(83));
+        assert_eq!(decode_calibration_value("abcone2threexyz"), Some(13));
+        assert_eq!(decode_calibration_value("xtwone3four"), Some(24));
+        assert_eq!(decode_calibration_value("4nineeightseven2"), Some(42));
+        assert_eq!(decode_calibration_value("zoneight234"), Some(14));
+        assert_eq!(decode_calibration_value("7pqrstsixteen"), Some(76));
 
         // custom tests
-        assert_eq!(decode_calibration_value("abconetwone"), 11);
+        assert_eq!(decode_calibration_value("abconetwone"), Some(11));
+        assert_eq!(decode_calibration_value("éone"), Some(11));
+        assert_eq!(decode_calibration_value("abc"), None);
     }
 }
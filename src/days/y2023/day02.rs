@@ -1,60 +1,71 @@
-use sscanf::sscanf;
+use crate::parse::{parse_lines, LineParser, ParseError};
+use crate::solution::Solution;
 use std::cmp::max;
-use std::fs;
+use std::str::FromStr;
 
-fn main() {
-    let input = fs::read_to_string("inputs/02.txt").expect("Failed to read input file");
+pub struct Day02;
 
-    let answer1 = challenge_part1(&input);
-    println!("Day 02, Part 1: {}", answer1);
-    assert_eq!(answer1, 2716);
+impl Solution for Day02 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 2;
 
-    let answer2 = challenge_part2(&input);
-    println!("Day 02, Part 2: {}", answer2);
-    assert_eq!(answer2, 72227);
-}
+    type Input = Vec<Game>;
+    type Part1 = u32;
+    type Part2 = u32;
 
-fn challenge_part1(input: &str) -> u32 {
-    let available_cubes = CubeSet::from_str("12 red, 13 green, 14 blue");
-
-    // number of possible games with the given cubes in the bag
-    input
-        .split('\n')
-        .filter(|line| !line.is_empty())
-        .map(Game::from_str) // parse each value into game
-        .filter(|game| game.is_possible_with_only_cube_set(&available_cubes)) // filter to only possible with our cube set
-        .map(|possible_game| possible_game.id)
-        .sum()
-}
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // parse each line into a game
+        parse_lines(input)
+    }
+
+    fn part1(games: &Self::Input) -> Self::Part1 {
+        let available_cubes: CubeSet = "12 red, 13 green, 14 blue".parse().unwrap();
 
-fn challenge_part2(input: &str) -> u32 {
-    // sum of powers of minimum cube set for each game
-    input
-        .split('\n')
-        .filter(|line| !line.is_empty())
-        .map(|line| Game::from_str(line).minimum_cube_set().power()) // parse each value into game, get minimum cube set & calculate power
-        .sum() // sum powers
+        // number of possible games with the given cubes in the bag
+        games
+            .iter()
+            .filter(|game| game.is_possible_with_only_cube_set(&available_cubes)) // filter to only possible with our cube set
+            .map(|possible_game| possible_game.id)
+            .sum()
+    }
+
+    fn part2(games: &Self::Input) -> Self::Part2 {
+        // sum of powers of minimum cube set for each game
+        games
+            .iter()
+            .map(|game| game.minimum_cube_set().power()) // get minimum cube set & calculate power
+            .sum() // sum powers
+    }
 }
 
 // --------------------------- Game structure ---------------------------------------------
-struct Game {
+#[derive(Debug)]
+pub struct Game {
     id: u32,
     game_rounds: Vec<CubeSet>,
 }
 
-impl Game {
-    pub fn from_str(game_str: &str) -> Game {
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(game_str: &str) -> Result<Game, ParseError> {
         // "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green" -> Game
-        let (id, game_rounds_str) = sscanf!(game_str, "Game {u32}: {str}").unwrap();
+        let parser = LineParser::new(game_str);
+        let (id_str, game_rounds_str) = parser.split_once(game_str, ":")?;
 
-        Game {
+        Ok(Game {
             // "Game 12" -> 12
-            id,
+            id: parser.number(parser.strip_prefix(id_str, "Game ")?)?,
             // parse each provided round into a CubeSet
-            game_rounds: game_rounds_str.split(';').map(CubeSet::from_str).collect(),
-        }
+            game_rounds: game_rounds_str
+                .split(';')
+                .map(|round_str| parse_cube_set(&parser, round_str))
+                .collect::<Result<Vec<CubeSet>, ParseError>>()?,
+        })
     }
+}
 
+impl Game {
     pub fn is_possible_with_only_cube_set(&self, available_cubes: &CubeSet) -> bool {
         // whether all the rounds within this game could have been played with only the available cubes
         self.game_rounds
@@ -77,35 +88,43 @@ impl Game {
 }
 
 // --------------------------- CubeSet structure ---------------------------------------------
-#[derive(Default)]
-struct CubeSet {
+#[derive(Debug, Default)]
+pub struct CubeSet {
     blue: u32,
     red: u32,
     green: u32,
 }
 
-impl CubeSet {
-    pub fn from_str(cubes_str: &str) -> CubeSet {
-        // cubes_str: "12 blue, 5 red, 6 green"
-
-        // start with a zeroed CubeSet, not all colours may be provided in the cubes_str
-        let mut cube_set = CubeSet::default();
+impl FromStr for CubeSet {
+    type Err = ParseError;
 
-        for cube_color_str in cubes_str.split(',') {
-            // " 12 blue" -> 12, "blue"
-            let (count, color) = sscanf!(cube_color_str.trim(), "{u32} {str}").unwrap();
+    fn from_str(cubes_str: &str) -> Result<CubeSet, ParseError> {
+        parse_cube_set(&LineParser::new(cubes_str), cubes_str)
+    }
+}
 
-            match color {
-                "blue" => cube_set.blue = count,
-                "red" => cube_set.red = count,
-                "green" => cube_set.green = count,
-                _ => panic!("Unknown color: {}", color),
-            }
+// cubes_str: "12 blue, 5 red, 6 green", part of the line the parser's for (so errors have the right column)
+fn parse_cube_set(parser: &LineParser, cubes_str: &str) -> Result<CubeSet, ParseError> {
+    // start with a zeroed CubeSet, not all colours may be provided in the cubes_str
+    let mut cube_set = CubeSet::default();
+
+    for cube_color_str in cubes_str.split(',') {
+        // " 12 blue" -> 12, "blue"
+        let (count, color) = parser.split_once(cube_color_str.trim(), " ")?;
+        let count = parser.number(count)?;
+
+        match color {
+            "blue" => cube_set.blue = count,
+            "red" => cube_set.red = count,
+            "green" => cube_set.green = count,
+            _ => return Err(parser.error(color, "red, green or blue")),
         }
-
-        cube_set
     }
 
+    Ok(cube_set)
+}
+
+impl CubeSet {
     pub fn is_possible_with_only_cube_set(&self, available_cubes: &CubeSet) -> bool {
         // does the provided available_cubes, have enough cubes to replicate this cube_set?
         available_cubes.blue >= self.blue
@@ -122,10 +141,11 @@ impl CubeSet {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::answers::assert_answers;
 
     #[test]
     fn test_main() {
-        main();
+        assert_answers::<Day02>();
     }
 
     #[test]
@@ -139,17 +159,17 @@ Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
         "
         .trim();
 
-        assert_eq!(challenge_part1(example_input), 8);
+        assert_eq!(Day02::part1(&Day02::parse(example_input).unwrap()), 8);
     }
 
     #[test]
     fn test_cubeset_from_str() {
-        let cube_set = CubeSet::from_str("3 blue, 4 red");
+        let cube_set = CubeSet::from_str("3 blue, 4 red").unwrap();
         assert_eq!(cube_set.red, 4);
         assert_eq!(cube_set.green, 0);
         assert_eq!(cube_set.blue, 3);
 
-        let cube_set = CubeSet::from_str("1 red, 2 green, 6 blue");
+        let cube_set = CubeSet::from_str("1 red, 2 green, 6 blue").unwrap();
         assert_eq!(cube_set.red, 1);
         assert_eq!(cube_set.green, 2);
         assert_eq!(cube_set.blue, 6);
@@ -176,14 +196,16 @@ Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
 
     #[test]
     fn test_minimum_cube_set() {
-        let game = Game::from_str("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green");
+        let game =
+            Game::from_str("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green").unwrap();
         let mcs = game.minimum_cube_set();
         assert_eq!(mcs.red, 4);
         assert_eq!(mcs.green, 2);
         assert_eq!(mcs.blue, 6);
 
         let game =
-            Game::from_str("Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue");
+            Game::from_str("Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue")
+                .unwrap();
         let mcs = game.minimum_cube_set();
         assert_eq!(mcs.red, 1);
         assert_eq!(mcs.green, 3);
@@ -191,13 +213,33 @@ Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
 
         let game = Game::from_str(
             "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red",
-        );
+        )
+        .unwrap();
         let mcs = game.minimum_cube_set();
         assert_eq!(mcs.red, 20);
         assert_eq!(mcs.green, 13);
         assert_eq!(mcs.blue, 6);
     }
 
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            "Game 1: 3 blue, 4 purple"
+                .parse::<Game>()
+                .unwrap_err()
+                .to_string(),
+            "column 19: expected red, green or blue, found \"purple\""
+        );
+        assert_eq!("Game x: 3 blue".parse::<Game>().unwrap_err().column, 6);
+
+        // truncated
+        let err = parse_lines::<Game>("Game 1: 3 blue\nGame 2").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected \":\", found \"Game 2\""
+        );
+    }
+
     #[test]
     fn test_example_input_part2() {
         let example_input = "
@@ -209,6 +251,6 @@ Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
         "
         .trim();
 
-        assert_eq!(challenge_part2(example_input), 2286);
+        assert_eq!(Day02::part2(&Day02::parse(example_input).unwrap()), 2286);
     }
 }
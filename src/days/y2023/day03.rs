@@ -0,0 +1,198 @@
+use crate::geometry::Point2;
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 3;
+
+    type Input = Grid<char>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(grid: &Self::Input) -> Self::Part1 {
+        let numbers = EngineNumber::from_grid(grid);
+
+        // find all numbers adjacent to symbols & sum them up
+        numbers
+            .iter()
+            .filter(|num| num.has_surrounding_symbol(grid))
+            .map(|num| num.value)
+            .sum()
+    }
+
+    fn part2(grid: &Self::Input) -> Self::Part2 {
+        let numbers = EngineNumber::from_grid(grid);
+
+        // find all gears & sum their ratios
+        grid
+            // find all stars
+            .find_all(|char| *char == '*')
+            // gears are stars with exactly 2 surrounding numbers
+            // find them, and return their gear ratio
+            .filter_map(|position| {
+                // brute force check every number to see if it's adjacent to this gear
+                // filter down to a list of adjacent_numbers
+                let adjacent_numbers: Vec<&EngineNumber> = numbers
+                    .iter()
+                    .filter(|num| {
+                        grid.neighbours8(position)
+                            .any(|neighbour| num.is_at_position(neighbour))
+                    })
+                    .collect();
+
+                if adjacent_numbers.len() == 2 {
+                    // found a gear - return it's ratio
+                    Some(adjacent_numbers[0].value * adjacent_numbers[1].value)
+                } else {
+                    // not exactly 2 numbers? Must not be a gear
+                    None
+                }
+            })
+            .sum()
+    }
+}
+
+struct EngineNumber {
+    // the first digit
+    position: Point2,
+    length: i32,
+    value: u32,
+}
+
+impl EngineNumber {
+    fn from_grid(grid: &Grid<char>) -> Vec<EngineNumber> {
+        grid.rows()
+            .enumerate()
+            .flat_map(|(y, row)| EngineNumber::from_row(y, row))
+            .collect()
+    }
+
+    fn from_row(y: usize, row: &[char]) -> Vec<EngineNumber> {
+        let mut engine_numbers: Vec<EngineNumber> = Vec::new();
+
+        let mut numbers_chars: Vec<char> = Vec::new();
+        // a trailing '.' flushes a number that runs to the end of the row
+        for (x, char) in row.iter().chain(['.'].iter()).enumerate() {
+            if char.is_ascii_digit() {
+                numbers_chars.push(*char);
+                continue;
+            }
+
+            // non digit character, check if we have number(s) in the buffer
+            if !numbers_chars.is_empty() {
+                // we have a number to add
+                let num = numbers_chars
+                    .iter()
+                    .collect::<String>()
+                    .parse::<u32>()
+                    .unwrap();
+
+                let length = numbers_chars.len() as i32;
+                engine_numbers.push(EngineNumber {
+                    position: Point2::new(x as i32 - length, y as i32),
+                    length,
+                    value: num,
+                });
+                numbers_chars.clear();
+            }
+        }
+
+        engine_numbers
+    }
+
+    fn is_at_position(&self, position: Point2) -> bool {
+        position.y == self.position.y
+            && (self.position.x..self.position.x + self.length).contains(&position.x)
+    }
+
+    fn has_surrounding_symbol(&self, grid: &Grid<char>) -> bool {
+        // anything that isn't a digit or a '.' is a symbol
+        (self.position.x..self.position.x + self.length)
+            .flat_map(|x| grid.neighbours8(Point2::new(x, self.position.y)))
+            .filter_map(|neighbour| grid.get(neighbour))
+            .any(|char| !char.is_ascii_digit() && *char != '.')
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::assert_answers;
+
+    #[test]
+    fn test_main() {
+        assert_answers::<Day03>();
+    }
+
+    #[test]
+    fn test_challenge_part1_example() {
+        let example_input = "
+...........
+.467..114..
+....*......
+...35..633.
+.......#...
+.617*......
+......+.58.
+...592.....
+.......755.
+....$.*....
+..664.598..
+..........."
+            .trim();
+
+        assert_eq!(Day03::part1(&Day03::parse(example_input).unwrap()), 4361);
+    }
+
+    #[test]
+    fn test_challenge_part2_example() {
+        let example_input = "
+...........
+.467..114..
+....*......
+...35..633.
+.......#...
+.617*......
+......+.58.
+...592.....
+.......755.
+....$.*....
+..664.598..
+..........."
+            .trim();
+
+        assert_eq!(Day03::part2(&Day03::parse(example_input).unwrap()), 467835);
+    }
+
+    #[test]
+    fn test_numbers_on_the_edges() {
+        // the puzzle's example, without the border of '.'s
+        let example_input = "
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+        "
+        .trim();
+
+        assert_eq!(Day03::part1(&Day03::parse(example_input).unwrap()), 4361);
+        assert_eq!(Day03::part2(&Day03::parse(example_input).unwrap()), 467835);
+
+        // numbers ending on the right edge
+        assert_eq!(Day03::part1(&Day03::parse("..*\n.12").unwrap()), 12);
+    }
+}
@@ -0,0 +1,188 @@
+use crate::parse::{parse_lines, LineParser, ParseError};
+use crate::solution::Solution;
+use std::str::FromStr;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 4;
+
+    type Input = Vec<ScratchCard>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(cards: &Self::Input) -> Self::Part1 {
+        cards.iter().map(|scratch_card| scratch_card.score()).sum()
+    }
+
+    fn part2(cards: &Self::Input) -> Self::Part2 {
+        // we mutate the counts of cards possessed as we go, so work on our own copy
+        let mut cards = cards.clone();
+
+        // loop through all cards, count how many we won, increase our count for each
+        for card in cards.clone().iter() {
+            // loop through all the cards we've won
+            for card_won_index in
+                card.card_number..(card.card_number + card.count_winning_numbers())
+            {
+                // increase the number of cards possessed for each card won
+                // we increase this for every card we own of this number
+
+                cards[card_won_index as usize].number_of_cards_possessed +=
+                    // gotta look up the card (rather than use &card) because we may have mutated it earlier
+                    // and the loop is running off a clone of the card set
+                    cards[card.card_number as usize - 1].number_of_cards_possessed;
+            }
+        }
+
+        // sum up the total cards we have
+        cards
+            .iter()
+            .map(|card| card.number_of_cards_possessed)
+            .sum()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ScratchCard {
+    card_number: u32,
+    winning_numbers: Vec<u32>,
+    my_numbers: Vec<u32>,
+    number_of_cards_possessed: usize,
+}
+
+impl FromStr for ScratchCard {
+    type Err = ParseError;
+
+    fn from_str(card_str: &str) -> Result<ScratchCard, ParseError> {
+        // "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53"
+        let parser = LineParser::new(card_str);
+        let (card_number_str, numbers_str) = parser.split_once(card_str, ":")?;
+        let (winning_numbers_str, my_numbers_str) = parser.split_once(numbers_str, "|")?;
+
+        Ok(ScratchCard {
+            card_number: parser.number(parser.strip_prefix(card_number_str, "Card")?)?,
+            winning_numbers: parser.numbers(winning_numbers_str)?,
+            my_numbers: parser.numbers(my_numbers_str)?,
+            number_of_cards_possessed: 1,
+        })
+    }
+}
+
+impl ScratchCard {
+    pub fn count_winning_numbers(&self) -> u32 {
+        self.my_numbers
+            .iter()
+            .filter(|num| self.winning_numbers.contains(num))
+            .count() as u32
+    }
+
+    pub fn score(&self) -> usize {
+        if self.count_winning_numbers() == 0 {
+            return 0;
+        }
+        i32::pow(2, self.count_winning_numbers() - 1) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::assert_answers;
+
+    #[test]
+    fn test_main() {
+        assert_answers::<Day04>();
+    }
+
+    #[test]
+    fn test_example_cards() {
+        assert_eq!(
+            ScratchCard::from_str("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53")
+                .unwrap()
+                .score(),
+            8
+        );
+        assert_eq!(
+            ScratchCard::from_str("Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19")
+                .unwrap()
+                .score(),
+            2
+        );
+        assert_eq!(
+            ScratchCard::from_str("Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1")
+                .unwrap()
+                .score(),
+            2
+        );
+        assert_eq!(
+            ScratchCard::from_str("Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83")
+                .unwrap()
+                .score(),
+            1
+        );
+        assert_eq!(
+            ScratchCard::from_str("Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36")
+                .unwrap()
+                .score(),
+            0
+        );
+        assert_eq!(
+            ScratchCard::from_str("Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11")
+                .unwrap()
+                .score(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            ScratchCard::from_str("Card 1: 41 48 83 | 83 8x 6")
+                .unwrap_err()
+                .to_string(),
+            "column 23: expected a number, found \"8x\""
+        );
+
+        let err = parse_lines::<ScratchCard>("Card 1: 41 | 83\nCard 2: 13 32").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 8: expected \"|\", found \" 13 32\""
+        );
+    }
+
+    #[test]
+    fn test_example_input_part_1() {
+        let example_input = "
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+        "
+        .trim();
+
+        assert_eq!(Day04::part1(&Day04::parse(example_input).unwrap()), 13);
+    }
+
+    #[test]
+    fn test_example_input_part_2() {
+        let example_input = "
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+        "
+        .trim();
+
+        assert_eq!(Day04::part2(&Day04::parse(example_input).unwrap()), 30);
+    }
+}
@@ -0,0 +1,660 @@
+use crate::interval::{Interval, IntervalSet};
+use crate::parse::{numbered_lines, records, LineParser, ParseError};
+use crate::solution::Solution;
+use std::str::FromStr;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 5;
+
+    type Input = Almanac;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_almanac(input)
+    }
+
+    fn part1(almanac: &Self::Input) -> Self::Part1 {
+        // look each seed up in the combined seed -> location mapping
+        let mapped_seeds = almanac
+            .seeds
+            .iter()
+            .map(|seed| almanac.seed_to_location.map_value(*seed));
+
+        // find the lowest final value
+        mapped_seeds.min().unwrap()
+    }
+
+    fn part2(almanac: &Self::Input) -> Self::Part2 {
+        // push whole ranges of seeds through, splitting them wherever
+        // part of a range maps somewhere different to the rest
+        let seeds: IntervalSet = almanac.seed_ranges().into_iter().collect();
+        let locations = almanac.seed_to_location.map_intervals(&seeds);
+
+        // the start of the lowest range
+        locations.min().unwrap()
+    }
+}
+
+fn parse_almanac(input: &str) -> Result<Almanac, ParseError> {
+    let records = records(input);
+    let Some((seeds_record, mapping_records)) = records.split_first() else {
+        return Err(ParseError::end_of_input(input, "\"seeds:\""));
+    };
+
+    // "seeds: 1 2 3 4" -> vec![1, 2, 3, 4]
+    let seeds_line = seeds_record.lines().next().unwrap();
+    let seeds = seeds_line.parse_with(|parser, text| {
+        let values = parser.value_for(text, "seeds")?;
+        let seeds = parser.numbers(values)?;
+        if seeds.is_empty() {
+            return Err(parser.error(values, "a seed"));
+        }
+
+        // part 2 reads them as "start length" pairs
+        if seeds.len() % 2 == 1 {
+            return Err(parser.error("", "a seed range length"));
+        }
+        let lengths = values.split_whitespace().skip(1).step_by(2);
+        for (pair, length) in seeds.chunks(2).zip(lengths) {
+            if pair[1] < 1 {
+                return Err(parser.error(length, "a range length of at least 1"));
+            }
+            if Interval::with_length(pair[0], pair[1]).is_none() {
+                return Err(parser.error(length, "a range that fits in an i64"));
+            }
+        }
+
+        Ok(seeds)
+    })?;
+
+    // treat the rest of the file as mappings
+    let mappings = mapping_records
+        .iter()
+        .map(|record| record.parse())
+        .collect::<Result<Vec<Mapping>, ParseError>>()?;
+
+    // every layer squashed into one, so a seed is a single lookup
+    let mut seed_to_location = Mapping::default();
+    for (record, mapping) in mapping_records.iter().zip(&mappings) {
+        seed_to_location = seed_to_location.compose(mapping).ok_or_else(|| {
+            let header = record.lines().next().unwrap();
+            LineParser::new(header.text)
+                .error(
+                    header.text,
+                    "mappings that move values less than an i64 in total",
+                )
+                .with_line(header.number)
+        })?;
+    }
+
+    Ok(Almanac {
+        seeds,
+        location_to_seed: seed_to_location.inverse(),
+        seed_to_location,
+    })
+}
+
+#[derive(Clone, Debug)]
+pub struct Almanac {
+    seeds: Vec<i64>,
+    seed_to_location: Mapping,
+    location_to_seed: InverseMapping,
+}
+
+impl Almanac {
+    // part 2 reads the seeds as pairs of "start length"
+    pub fn seed_ranges(&self) -> Vec<Interval> {
+        self.seeds
+            .chunks(2)
+            .map(|pair| Interval::with_length(pair[0], pair[1]).expect("Checked when parsing"))
+            .collect()
+    }
+
+    // which seeds end up at this location, lowest first
+    pub fn seeds_for_location(&self, location: i64) -> Vec<i64> {
+        self.location_to_seed.preimages(location)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct MappingRange {
+    source: Interval,
+    src_dst_delta: i64,
+}
+
+impl FromStr for MappingRange {
+    type Err = ParseError;
+
+    fn from_str(range_str: &str) -> Result<MappingRange, ParseError> {
+        // "50 98 2" -> destination start, source start, length
+        let parser = LineParser::new(range_str);
+        let [dst_start, src_start, range_length] = parser.numbers::<i64>(range_str)?[..] else {
+            return Err(parser.error(range_str, "3 numbers"));
+        };
+        if range_length < 1 {
+            return Err(parser.error(range_str, "a range length of at least 1"));
+        }
+
+        // both ranges & the distance between them, either way, have to fit in an i64
+        let src_dst_delta = dst_start
+            .checked_sub(src_start)
+            .filter(|_| src_start.checked_sub(dst_start).is_some());
+        let (Some(source), Some(_), Some(src_dst_delta)) = (
+            Interval::with_length(src_start, range_length),
+            Interval::with_length(dst_start, range_length),
+            src_dst_delta,
+        ) else {
+            return Err(parser.error(range_str, "ranges that fit in an i64"));
+        };
+
+        Ok(MappingRange {
+            source,
+            src_dst_delta,
+        })
+    }
+}
+
+impl MappingRange {
+    // part of the source, moved to where it ends up
+    fn map_interval(&self, source: &Interval) -> Interval {
+        source
+            .shift(self.src_dst_delta)
+            .expect("Mapped past the end of the i64s")
+    }
+
+    // part of where the source ends up, moved back again
+    fn unmap_interval(&self, destination: &Interval) -> Interval {
+        destination
+            .shift(-self.src_dst_delta)
+            .expect("Mapped past the end of the i64s")
+    }
+
+    pub fn map_value(&self, source_value: i64) -> Option<i64> {
+        if self.source.contains(source_value) {
+            return Some(source_value + self.src_dst_delta);
+        }
+
+        None
+    }
+}
+
+// sorted by source, without overlaps, so a value is a binary search away from its range
+// values outside every range map to themselves, the default mapping changes nothing
+#[derive(Clone, Debug, Default)]
+struct Mapping {
+    ranges: Vec<MappingRange>,
+}
+
+impl FromStr for Mapping {
+    type Err = ParseError;
+
+    fn from_str(mapping_str: &str) -> Result<Mapping, ParseError> {
+        let mut lines = numbered_lines(mapping_str);
+
+        // skip the "seed-to-soil map:" header
+        let header = lines.next().map_or("", |line| line.text);
+        if !header.ends_with("map:") {
+            return Err(LineParser::new(header)
+                .error(header, "a \"map:\" header")
+                .with_line(1));
+        }
+
+        Ok(Mapping::new(
+            lines
+                .map(|line| line.parse())
+                .collect::<Result<Vec<MappingRange>, ParseError>>()?,
+        ))
+    }
+}
+
+impl Mapping {
+    // where ranges overlap the first one listed wins, the way the almanac reads
+    fn new(ranges: Vec<MappingRange>) -> Mapping {
+        let mut covered = IntervalSet::new();
+        let mut pieces = vec![];
+
+        for range in ranges {
+            let uncovered = IntervalSet::from(range.source).difference(&covered);
+            pieces.extend(uncovered.iter().map(|source| MappingRange {
+                source: *source,
+                src_dst_delta: range.src_dst_delta,
+            }));
+            covered.insert(range.source);
+        }
+
+        Mapping::from_pieces(pieces)
+    }
+
+    // pieces that already don't overlap
+    fn from_pieces(mut pieces: Vec<MappingRange>) -> Mapping {
+        // a range that doesn't move anything is the same as no range
+        pieces.retain(|piece| piece.src_dst_delta != 0);
+        pieces.sort_by_key(|piece| piece.source);
+
+        Mapping { ranges: pieces }
+    }
+
+    fn range_for(&self, source_value: i64) -> Option<&MappingRange> {
+        let idx = self
+            .ranges
+            .partition_point(|range| range.source.end() < source_value);
+
+        self.ranges
+            .get(idx)
+            .filter(|range| range.source.contains(source_value))
+    }
+
+    pub fn map_value(&self, source_value: i64) -> i64 {
+        self.range_for(source_value)
+            .and_then(|range| range.map_value(source_value))
+            // no valid mapping range, return original value as-is
+            .unwrap_or(source_value)
+    }
+
+    // map_value for every value at once
+    fn map_intervals(&self, source_values: &IntervalSet) -> IntervalSet {
+        let mut unmapped = source_values.clone();
+        let mut mapped = IntervalSet::new();
+
+        for range in &self.ranges {
+            let covered = unmapped.intersection(&range.source.into());
+            for interval in covered.iter() {
+                mapped.insert(range.map_interval(interval));
+            }
+            unmapped = unmapped.difference(&covered);
+        }
+
+        // anything left over keeps its value
+        mapped.union(&unmapped)
+    }
+
+    // one mapping doing self then next, i.e. seed-to-soil + soil-to-fertilizer = seed-to-fertilizer
+    // None when a value would be moved further than an i64 can say, either way
+    fn compose(&self, next: &Mapping) -> Option<Mapping> {
+        let mut pieces = vec![];
+
+        for range in &self.ranges {
+            // where this range's values land, split up by the ranges of next they land in
+            let landed = IntervalSet::from(range.map_interval(&range.source));
+            let mut unmapped = landed.clone();
+
+            for next_range in &next.ranges {
+                let covered = landed.intersection(&next_range.source.into());
+                if covered.is_empty() {
+                    continue;
+                }
+                let src_dst_delta = range
+                    .src_dst_delta
+                    .checked_add(next_range.src_dst_delta)
+                    .filter(|delta| delta.checked_neg().is_some())?;
+                pieces.extend(covered.iter().map(|interval| MappingRange {
+                    source: range.unmap_interval(interval),
+                    src_dst_delta,
+                }));
+                unmapped = unmapped.difference(&covered);
+            }
+
+            // landed outside next's ranges, next leaves them there
+            pieces.extend(unmapped.iter().map(|interval| MappingRange {
+                source: range.unmap_interval(interval),
+                src_dst_delta: range.src_dst_delta,
+            }));
+        }
+
+        // values self leaves alone only go through next
+        let moved: IntervalSet = self.ranges.iter().map(|range| range.source).collect();
+        for next_range in &next.ranges {
+            let untouched = IntervalSet::from(next_range.source).difference(&moved);
+            pieces.extend(untouched.iter().map(|interval| MappingRange {
+                source: *interval,
+                src_dst_delta: next_range.src_dst_delta,
+            }));
+        }
+
+        Some(Mapping::from_pieces(pieces))
+    }
+
+    // the other way around, from a mapped value back to every source value that lands on it
+    fn inverse(&self) -> InverseMapping {
+        // where each range lands & how to get back, the gaps between ranges stay put
+        let mut landings: Vec<(Interval, i64)> = self
+            .ranges
+            .iter()
+            .map(|range| (range.map_interval(&range.source), -range.src_dst_delta))
+            .collect();
+        let moved: IntervalSet = self.ranges.iter().map(|range| range.source).collect();
+        let everything = IntervalSet::from(Interval::new(i64::MIN, i64::MAX));
+        landings.extend(everything.difference(&moved).iter().map(|gap| (*gap, 0)));
+
+        // several ranges can land on the same values, so cut the destinations up wherever
+        // one starts or ends, leaving pieces that don't overlap, each with every way back
+        let mut boundaries: Vec<i64> = landings
+            .iter()
+            .flat_map(|(destination, _)| {
+                [Some(destination.start()), destination.end().checked_add(1)]
+            })
+            .flatten()
+            .collect();
+        boundaries.sort();
+        boundaries.dedup();
+
+        let mut pieces = vec![];
+        for (idx, start) in boundaries.iter().enumerate() {
+            let end = boundaries
+                .get(idx + 1)
+                .map_or(i64::MAX, |next_start| next_start - 1);
+            let piece = Interval::new(*start, end);
+            let mut offsets: Vec<i64> = landings
+                .iter()
+                .filter(|(destination, _)| destination.contains_interval(&piece))
+                .map(|(_, offset)| *offset)
+                .collect();
+            if !offsets.is_empty() {
+                // lowest source value first
+                offsets.sort();
+                pieces.push((piece, offsets));
+            }
+        }
+
+        InverseMapping { pieces }
+    }
+}
+
+// sorted by mapped value, without overlaps, each piece with the offsets back to its sources
+#[derive(Clone, Debug)]
+struct InverseMapping {
+    pieces: Vec<(Interval, Vec<i64>)>,
+}
+
+impl InverseMapping {
+    // every source value that maps to this one, lowest first
+    fn preimages(&self, mapped_value: i64) -> Vec<i64> {
+        let idx = self
+            .pieces
+            .partition_point(|(piece, _)| piece.end() < mapped_value);
+
+        self.pieces
+            .get(idx)
+            .filter(|(piece, _)| piece.contains(mapped_value))
+            .map_or_else(Vec::new, |(_, offsets)| {
+                offsets.iter().map(|offset| mapped_value + offset).collect()
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::assert_answers;
+
+    const EXAMPLE_INPUT: &str = "
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
+
+    #[test]
+    fn test_main() {
+        assert_answers::<Day05>();
+    }
+
+    #[test]
+    fn test_parse_mapping() {
+        let example_input = "
+seed-to-soil map:
+50 98 2
+52 50 48
+        "
+        .trim();
+
+        let mapping = Mapping::from_str(example_input).unwrap();
+
+        assert_eq!(mapping.ranges.len(), 2);
+
+        // sorted by source
+        assert_eq!(mapping.ranges[1].source, Interval::new(98, 99));
+        assert_eq!(mapping.ranges[1].src_dst_delta, -48);
+
+        assert_eq!(mapping.map_value(0), 0);
+        assert_eq!(mapping.map_value(1), 1);
+        assert_eq!(mapping.map_value(49), 49);
+        assert_eq!(mapping.map_value(50), 52);
+        assert_eq!(mapping.map_value(51), 53);
+        assert_eq!(mapping.map_value(96), 98);
+        assert_eq!(mapping.map_value(97), 99);
+        assert_eq!(mapping.map_value(98), 50);
+        assert_eq!(mapping.map_value(99), 51);
+
+        // 45-49 keeps its value, 50-97 lands right after it & 98-99 fills the gap it left
+        let mapped = mapping.map_intervals(&Interval::new(45, 99).into());
+        assert_eq!(
+            mapped.iter().copied().collect::<Vec<Interval>>(),
+            vec![Interval::new(45, 99)]
+        );
+        // split where the second range ends
+        let mapped = mapping.map_intervals(&Interval::new(96, 99).into());
+        assert_eq!(
+            mapped.iter().copied().collect::<Vec<Interval>>(),
+            vec![Interval::new(50, 51), Interval::new(98, 99)]
+        );
+    }
+
+    #[test]
+    fn test_overlapping_ranges() {
+        // 10-14 is covered twice, the first range wins
+        let mapping = Mapping::from_str("a-to-b map:\n100 10 5\n200 5 10\n").unwrap();
+
+        assert_eq!(mapping.map_value(12), 102);
+        assert_eq!(mapping.map_value(7), 202);
+        assert_eq!(mapping.ranges.len(), 2);
+
+        // mapping to where it already is isn't a range
+        let mapping = Mapping::from_str("a-to-b map:\n5 5 10\n").unwrap();
+        assert!(mapping.ranges.is_empty());
+    }
+
+    #[test]
+    fn test_compose() {
+        let records = records(EXAMPLE_INPUT);
+        let layers: Vec<Mapping> = records[1..]
+            .iter()
+            .map(|record| record.parse().unwrap())
+            .collect();
+        let almanac = Day05::parse(EXAMPLE_INPUT).unwrap();
+
+        // the same as going through every layer in turn
+        for seed in 0..=110 {
+            let layer_by_layer = layers
+                .iter()
+                .fold(seed, |value, mapping| mapping.map_value(value));
+            assert_eq!(almanac.seed_to_location.map_value(seed), layer_by_layer);
+        }
+
+        // the example's seeds
+        let locations: Vec<i64> = [79, 14, 55, 13]
+            .iter()
+            .map(|seed| almanac.seed_to_location.map_value(*seed))
+            .collect();
+        assert_eq!(locations, vec![82, 43, 86, 35]);
+
+        // composing with nothing changes nothing
+        let soil = &layers[0];
+        assert_eq!(
+            Mapping::default().compose(soil).unwrap().ranges,
+            soil.ranges
+        );
+        assert_eq!(
+            soil.compose(&Mapping::default()).unwrap().ranges,
+            soil.ranges
+        );
+    }
+
+    #[test]
+    fn test_preimages() {
+        let almanac = Day05::parse(EXAMPLE_INPUT).unwrap();
+
+        // part 2's lowest location
+        assert_eq!(almanac.seeds_for_location(46), vec![82]);
+        assert_eq!(almanac.seeds_for_location(35), vec![13]);
+
+        // both 50 & 98 end up at 50
+        let mapping = Mapping::from_str("seed-to-soil map:\n50 98 2\n").unwrap();
+        assert_eq!(mapping.inverse().preimages(50), vec![50, 98]);
+        // 98 moved away & nothing moved onto it
+        assert_eq!(mapping.inverse().preimages(98), vec![]);
+        // values nowhere near a range map back to themselves, all the way out
+        assert_eq!(mapping.inverse().preimages(i64::MAX), vec![i64::MAX]);
+        assert_eq!(mapping.inverse().preimages(-5), vec![-5]);
+
+        // every preimage maps back
+        for location in 0..=110 {
+            for seed in almanac.seeds_for_location(location) {
+                assert_eq!(almanac.seed_to_location.map_value(seed), location);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            MappingRange::from_str("50 98").unwrap_err().to_string(),
+            "column 1: expected 3 numbers, found \"50 98\""
+        );
+        assert_eq!(
+            MappingRange::from_str("50 98 0").unwrap_err().to_string(),
+            "column 1: expected a range length of at least 1, found \"50 98 0\""
+        );
+        assert_eq!(
+            MappingRange::from_str("50 9223372036854775807 2")
+                .unwrap_err()
+                .to_string(),
+            "column 1: expected ranges that fit in an i64, found \"50 9223372036854775807 2\""
+        );
+        assert!(MappingRange::from_str("-9223372036854775808 0 1").is_err());
+        assert!(MappingRange::from_str("9223372036854775806 0 1").is_ok());
+
+        // lines are counted from the top of the whole input
+        let err =
+            parse_almanac("seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 x 48\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 5, column 4: expected a number, found \"x\""
+        );
+
+        let err = parse_almanac("seeds: 79 14\n\n50 98 2\n").unwrap_err();
+        assert_eq!(err.line, Some(3));
+
+        assert_eq!(
+            parse_almanac("\n").unwrap_err().to_string(),
+            "line 2, column 1: expected \"seeds:\", found the end of the input"
+        );
+
+        // part 2's seed ranges need a length of at least 1 each
+        assert_eq!(
+            parse_almanac("seeds: 79 14 55\n").unwrap_err().to_string(),
+            "line 1, column 16: expected a seed range length, found the end of the line"
+        );
+        assert_eq!(
+            parse_almanac("seeds: 79 14 55 0\n")
+                .unwrap_err()
+                .to_string(),
+            "line 1, column 17: expected a range length of at least 1, found \"0\""
+        );
+        assert_eq!(
+            parse_almanac("seeds: 9223372036854775807 2\n")
+                .unwrap_err()
+                .to_string(),
+            "line 1, column 28: expected a range that fits in an i64, found \"2\""
+        );
+
+        assert_eq!(
+            parse_almanac("seeds:\n").unwrap_err().to_string(),
+            "line 1, column 7: expected a seed, found the end of the line"
+        );
+
+        let err = parse_almanac("seed: 79 14\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: expected \"seeds\", found \"seed\""
+        );
+
+        // windows line endings
+        let almanac =
+            parse_almanac("seeds: 79 14\r\n\r\nseed-to-soil map:\r\n50 98 2\r\n").unwrap();
+        assert_eq!(almanac.seeds, vec![79, 14]);
+        assert_eq!(almanac.seed_to_location.ranges[0].source.end(), 99);
+    }
+
+    #[test]
+    fn test_i64_limits() {
+        // each layer moves by less than an i64, both of them together don't
+        let input = "seeds: 4700000000000000000 1
+
+seed-to-soil map:
+0 4700000000000000000 1
+
+soil-to-fertilizer map:
+-4700000000000000000 0 1
+";
+        assert_eq!(
+            parse_almanac(input).unwrap_err().to_string(),
+            "line 6, column 1: expected mappings that move values less than an i64 in total, \
+             found \"soil-to-fertilizer map:\""
+        );
+
+        // back the other way is fine, the seed ends up where it started
+        let input = input.replace("\n-4700000000000000000 0 1", "\n4700000000000000000 0 1");
+        let almanac = parse_almanac(&input).unwrap();
+        assert_eq!(
+            almanac.seed_to_location.map_value(4700000000000000000),
+            4700000000000000000
+        );
+        assert_eq!(Day05::part2(&almanac), 4700000000000000000);
+    }
+
+    #[test]
+    fn test_example_input_1() {
+        let answer = Day05::part1(&Day05::parse(EXAMPLE_INPUT).unwrap());
+        assert_eq!(answer, 35);
+    }
+
+    #[test]
+    fn test_example_input_2() {
+        let answer = Day05::part2(&Day05::parse(EXAMPLE_INPUT).unwrap());
+        assert_eq!(answer, 46);
+    }
+}
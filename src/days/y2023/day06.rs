@@ -0,0 +1,203 @@
+use crate::parse::{numbered_lines, LineParser, ParseError};
+use crate::solution::Solution;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 6;
+
+    type Input = RaceSheet;
+    // part 1's product & part 2's one long race can both outgrow a u32
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // Time:        48     98     90     83
+        // Distance:   390   1103   1112   1360
+        let mut rows = numbered_lines(input);
+        let mut next_row = |key: &str| {
+            rows.next()
+                .ok_or_else(|| ParseError::end_of_input(input, &format!("{key:?}")))
+        };
+
+        let (durations, single_duration) = next_row("Time")?
+            .parse_with(|parser, text| parse_row(parser, parser.value_for(text, "Time")?))?;
+        let (record_distances, single_record_distance) =
+            next_row("Distance")?.parse_with(|parser, text| {
+                let value = parser.value_for(text, "Distance")?;
+                let row = parse_row(parser, value)?;
+                if row.0.len() != durations.len() {
+                    let expected = format!("{} distances, one per race", durations.len());
+                    return Err(parser.error(value, &expected));
+                }
+
+                Ok(row)
+            })?;
+
+        // part 1, each column is a separate race
+        let races = durations
+            .into_iter()
+            .zip(record_distances)
+            .map(|(duration, record_distance)| Race {
+                duration,
+                record_distance,
+            })
+            .collect();
+
+        // part 2, bad kerning - the columns are actually one big race
+        // Time:        48989083
+        // Distance:   390110311121360
+        let single_race = Race {
+            duration: single_duration,
+            record_distance: single_record_distance,
+        };
+
+        Ok(RaceSheet { races, single_race })
+    }
+
+    fn part1(race_sheet: &Self::Input) -> Self::Part1 {
+        challenge(&race_sheet.races)
+    }
+
+    fn part2(race_sheet: &Self::Input) -> Self::Part2 {
+        race_sheet.single_race.ways_to_beat_record()
+    }
+}
+
+// "7  15   30" -> ([7, 15, 30], 71530), each column and the columns read as one number
+fn parse_row(parser: &LineParser, value: &str) -> Result<(Vec<u64>, u64), ParseError> {
+    let columns = parser.numbers(value)?;
+    if columns.is_empty() {
+        return Err(parser.error(value, "a number"));
+    }
+    let kerned = value.split_whitespace().collect::<String>();
+    let single = kerned
+        .parse()
+        .map_err(|_| parser.error(value, "a number that fits in a u64"))?;
+
+    Ok((columns, single))
+}
+
+fn challenge(races: &[Race]) -> u64 {
+    races
+        .iter()
+        .map(|race| race.ways_to_beat_record())
+        .product()
+}
+
+#[derive(Debug)]
+pub struct RaceSheet {
+    races: Vec<Race>,
+    single_race: Race,
+}
+
+#[derive(Debug, PartialEq)]
+struct Race {
+    duration: u64,
+    record_distance: u64,
+}
+
+impl Race {
+    pub fn distance_for_button_hold(&self, button_hold_time: u64) -> u64 {
+        let boat_speed = button_hold_time;
+        let boat_move_time = self.duration - button_hold_time;
+
+        boat_speed * boat_move_time
+    }
+
+    pub fn ways_to_beat_record(&self) -> u64 {
+        let mut ways = 0;
+        for button_hold_time in 1..self.duration {
+            let distance = self.distance_for_button_hold(button_hold_time);
+            if distance > self.record_distance {
+                ways += 1;
+            }
+        }
+        ways
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::assert_answers;
+
+    #[test]
+    fn test_main() {
+        assert_answers::<Day06>();
+    }
+
+    #[test]
+    fn test_parse_invalid_race_sheet() {
+        let err = Day06::parse("Time:  7  15\r\n\r\nDist:  9  40\r\n").unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 1: expected \"Distance\", found \"Dist\""
+        );
+    }
+
+    #[test]
+    fn test_parse_non_numeric_race() {
+        let err = Day06::parse("Time: 7 x\nDistance: 9 40\n").unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 9: expected a number, found \"x\""
+        );
+    }
+
+    #[test]
+    fn test_parse_mismatched_columns() {
+        let err = Day06::parse("Time:  7  15\nDistance:  9\n").unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 12: expected 2 distances, one per race, found \"9\""
+        );
+
+        let err = Day06::parse("Time:\nDistance:\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 6: expected a number, found the end of the line"
+        );
+    }
+
+    #[test]
+    fn test_parse_missing_distances() {
+        let err = Day06::parse("Time:  7  15\n").unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected \"Distance\", found the end of the input"
+        );
+    }
+
+    #[test]
+    fn test_parse_race_sheet() {
+        let race_sheet = Day06::parse(
+            "
+Time:        48     98     90     83
+Distance:   390   1103   1112   1360
+            ",
+        )
+        .unwrap();
+
+        assert_eq!(race_sheet.races.len(), 4);
+        assert_eq!(
+            race_sheet.races[1],
+            Race {
+                duration: 98,
+                record_distance: 1103,
+            }
+        );
+        assert_eq!(
+            race_sheet.single_race,
+            Race {
+                duration: 48989083,
+                record_distance: 390110311121360,
+            }
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use crate::parse::{parse_lines, LineParser, ParseError};
+use crate::solution::Solution;
+
+mod part1;
+mod part2;
+
+// part 1 & 2 are solved separately in part1.rs & part2.rs, each with its own Hand
+// (J is a jack in part 1 & a joker in part 2), so the input's parsed once for each
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 7;
+
+    type Input = (Vec<part1::Hand>, Vec<part2::Hand>);
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok((parse_lines(input)?, parse_lines(input)?))
+    }
+
+    fn part1((hands, _): &Self::Input) -> Self::Part1 {
+        part1::challenge(hands)
+    }
+
+    fn part2((_, hands): &Self::Input) -> Self::Part2 {
+        part2::challenge(hands)
+    }
+}
+
+// "32T3K 765" -> the 5 cards, each read by `card`, & the bid
+// shared by both parts' Hand, which only differ in what a J is
+fn parse_hand<C>(
+    input: &str,
+    card: impl Fn(char) -> Option<C>,
+) -> Result<(Vec<C>, u32), ParseError> {
+    let parser = LineParser::new(input);
+    let (cards_str, bid_str) = parser.split_once(input, " ")?;
+
+    let cards = cards_str
+        .char_indices()
+        .map(|(idx, x)| {
+            card(x).ok_or_else(|| parser.error(&cards_str[idx..idx + x.len_utf8()], "a card"))
+        })
+        .collect::<Result<Vec<C>, ParseError>>()?;
+    if cards.len() != 5 {
+        return Err(parser.error(cards_str, "5 cards"));
+    }
+
+    Ok((cards, parser.number(bid_str)?))
+}
+
+// 2-9 & T, the number cards both parts agree on
+fn number_card_value(x: char) -> Option<i32> {
+    match x {
+        'T' => Some(10),
+        '2'..='9' => x.to_digit(10).map(|digit| digit as i32),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::assert_answers;
+
+    #[test]
+    fn test_main() {
+        assert_answers::<Day07>();
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Day07::parse("32T3K 765\n32X3K 765\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: expected a card, found \"X\""
+        );
+
+        // 0 & 1 aren't cards
+        assert_eq!(
+            Day07::parse("3213K 765").unwrap_err().to_string(),
+            "line 1, column 3: expected a card, found \"1\""
+        );
+        assert_eq!(
+            Day07::parse("32T3 765").unwrap_err().to_string(),
+            "line 1, column 1: expected 5 cards, found \"32T3\""
+        );
+        assert_eq!(
+            Day07::parse("32T3K 765\nT55J5").unwrap_err().to_string(),
+            "line 2, column 1: expected \" \", found \"T55J5\""
+        );
+        assert_eq!(
+            Day07::parse("32T3K x").unwrap_err().to_string(),
+            "line 1, column 7: expected a number, found \"x\""
+        );
+    }
+}
@@ -1,25 +1,11 @@
-use sscanf::sscanf;
+use crate::parse::ParseError;
 use std::cmp::Ordering;
 use std::collections::HashMap;
-use std::fs;
 use std::hash::Hash;
+use std::str::FromStr;
 
-fn main() {
-    let input = fs::read_to_string("inputs/07.txt").expect("Failed to read input file");
-
-    let answer = challenge(&input);
-
-    println!("Day 07, Part 1: {}", answer);
-    assert_eq!(answer, 253866470);
-}
-
-fn challenge(input: &str) -> u32 {
-    let mut hands: Vec<Hand> = input
-        .split('\n')
-        .filter(|x| !x.is_empty())
-        .map(Hand::from_str)
-        .collect();
-
+pub fn challenge(hands: &[Hand]) -> u32 {
+    let mut hands: Vec<&Hand> = hands.iter().collect();
     hands.sort();
 
     hands
@@ -38,6 +24,18 @@ enum Card {
     Ace,
 }
 
+impl Card {
+    fn from_char(x: char) -> Option<Card> {
+        match x {
+            'J' => Some(Card::Jack),
+            'Q' => Some(Card::Queen),
+            'K' => Some(Card::King),
+            'A' => Some(Card::Ace),
+            _ => super::number_card_value(x).map(Card::Number),
+        }
+    }
+}
+
 #[derive(Ord, PartialOrd, Eq, PartialEq)]
 enum HandType {
     HighCard,
@@ -50,7 +48,7 @@ enum HandType {
 }
 
 #[derive(Eq, PartialEq, Debug)]
-struct Hand {
+pub struct Hand {
     cards: Vec<Card>,
     bid: u32,
 }
@@ -73,26 +71,17 @@ impl PartialOrd for Hand {
     }
 }
 
-impl Hand {
-    fn from_str(input: &str) -> Hand {
-        let (cards_str, bid) = sscanf!(input, "{String} {u32}").unwrap();
-
-        Hand {
-            cards: cards_str
-                .chars()
-                .map(|x| match x {
-                    'J' => Card::Jack,
-                    'Q' => Card::Queen,
-                    'K' => Card::King,
-                    'A' => Card::Ace,
-                    'T' => Card::Number(10),
-                    _ => Card::Number(x.to_digit(10).unwrap() as i32),
-                })
-                .collect(),
-            bid,
-        }
+impl FromStr for Hand {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Hand, ParseError> {
+        let (cards, bid) = super::parse_hand(input, Card::from_char)?;
+
+        Ok(Hand { cards, bid })
     }
+}
 
+impl Hand {
     fn hand_type(&self) -> HandType {
         let mut card_counts: HashMap<&Card, usize> = HashMap::new();
         for card in self.cards.iter() {
@@ -133,11 +122,7 @@ impl Hand {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    #[test]
-    fn test_main() {
-        main();
-    }
+    use crate::parse::parse_lines;
 
     #[test]
     fn test_example_input() {
@@ -149,7 +134,11 @@ KTJJT 220
 QQQJA 483
         "
         .trim();
-        assert_eq!(challenge(example_input), 6440);
+        assert_eq!(challenge(&parse_lines(example_input).unwrap()), 6440);
+
+        // windows line endings
+        let hands = parse_lines(&example_input.replace('\n', "\r\n")).unwrap();
+        assert_eq!(challenge(&hands), 6440);
     }
 
     #[test]
@@ -178,22 +167,22 @@ QQQJA 483
     #[test]
     fn test_sort_hands() {
         let mut hands = vec![
-            Hand::from_str("32T3K 1"),
-            Hand::from_str("T55J5 2"),
-            Hand::from_str("KK677 3"),
-            Hand::from_str("KTJJT 4"),
-            Hand::from_str("QQQJA 5"),
+            Hand::from_str("32T3K 1").unwrap(),
+            Hand::from_str("T55J5 2").unwrap(),
+            Hand::from_str("KK677 3").unwrap(),
+            Hand::from_str("KTJJT 4").unwrap(),
+            Hand::from_str("QQQJA 5").unwrap(),
         ];
         hands.sort();
 
         assert_eq!(
             hands,
             vec![
-                Hand::from_str("32T3K 1"),
-                Hand::from_str("KTJJT 4"),
-                Hand::from_str("KK677 3"),
-                Hand::from_str("T55J5 2"),
-                Hand::from_str("QQQJA 5"),
+                Hand::from_str("32T3K 1").unwrap(),
+                Hand::from_str("KTJJT 4").unwrap(),
+                Hand::from_str("KK677 3").unwrap(),
+                Hand::from_str("T55J5 2").unwrap(),
+                Hand::from_str("QQQJA 5").unwrap(),
             ]
         );
     }
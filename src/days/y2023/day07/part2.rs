@@ -1,27 +1,11 @@
-use sscanf::sscanf;
+use crate::parse::ParseError;
 use std::cmp::Ordering;
 use std::collections::HashMap;
-use std::fs;
 use std::hash::Hash;
+use std::str::FromStr;
 
-// TODO: merge with 07a, toggle 'parseJAsJoker' flag
-
-fn main() {
-    let input = fs::read_to_string("inputs/07.txt").expect("Failed to read input file");
-
-    let answer = challenge(&input);
-
-    println!("Day 07, Part 2: {}", answer);
-    assert_eq!(answer, 254494947);
-}
-
-fn challenge(input: &str) -> u32 {
-    let mut hands: Vec<Hand> = input
-        .split('\n')
-        .filter(|x| !x.is_empty())
-        .map(Hand::from_str)
-        .collect();
-
+pub fn challenge(hands: &[Hand]) -> u32 {
+    let mut hands: Vec<&Hand> = hands.iter().collect();
     hands.sort();
 
     hands
@@ -40,6 +24,18 @@ enum Card {
     Ace,
 }
 
+impl Card {
+    fn from_char(x: char) -> Option<Card> {
+        match x {
+            'J' => Some(Card::Joker),
+            'Q' => Some(Card::Queen),
+            'K' => Some(Card::King),
+            'A' => Some(Card::Ace),
+            _ => super::number_card_value(x).map(Card::Number),
+        }
+    }
+}
+
 #[derive(Ord, PartialOrd, Eq, PartialEq, Debug)]
 enum HandType {
     HighCard,
@@ -52,7 +48,7 @@ enum HandType {
 }
 
 #[derive(Eq, PartialEq, Debug)]
-struct Hand {
+pub struct Hand {
     cards: Vec<Card>,
     bid: u32,
 }
@@ -75,26 +71,17 @@ impl PartialOrd for Hand {
     }
 }
 
-impl Hand {
-    fn from_str(input: &str) -> Hand {
-        let (cards_str, bid) = sscanf!(input, "{String} {u32}").unwrap();
+impl FromStr for Hand {
+    type Err = ParseError;
 
-        Hand {
-            cards: cards_str
-                .chars()
-                .map(|x| match x {
-                    'J' => Card::Joker,
-                    'Q' => Card::Queen,
-                    'K' => Card::King,
-                    'A' => Card::Ace,
-                    'T' => Card::Number(10),
-                    _ => Card::Number(x.to_digit(10).unwrap() as i32),
-                })
-                .collect(),
-            bid,
-        }
+    fn from_str(input: &str) -> Result<Hand, ParseError> {
+        let (cards, bid) = super::parse_hand(input, Card::from_char)?;
+
+        Ok(Hand { cards, bid })
     }
+}
 
+impl Hand {
     fn hand_type(&self) -> HandType {
         let mut card_counts: HashMap<&Card, usize> = HashMap::new();
         for card in self.cards.iter() {
@@ -172,11 +159,7 @@ impl Hand {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    #[test]
-    fn test_main() {
-        main();
-    }
+    use crate::parse::parse_lines;
 
     #[test]
     fn test_example_input() {
@@ -188,7 +171,7 @@ KTJJT 220
 QQQJA 483
         "
         .trim();
-        assert_eq!(challenge(example_input), 5905);
+        assert_eq!(challenge(&parse_lines(example_input).unwrap()), 5905);
     }
 
     #[test]
@@ -217,62 +200,110 @@ QQQJA 483
     #[test]
     fn test_sort_hands() {
         let mut hands = vec![
-            Hand::from_str("32T3K 1"),
-            Hand::from_str("T55J5 2"),
-            Hand::from_str("KK677 3"),
-            Hand::from_str("KTJJT 4"),
-            Hand::from_str("QQQJA 5"),
+            Hand::from_str("32T3K 1").unwrap(),
+            Hand::from_str("T55J5 2").unwrap(),
+            Hand::from_str("KK677 3").unwrap(),
+            Hand::from_str("KTJJT 4").unwrap(),
+            Hand::from_str("QQQJA 5").unwrap(),
         ];
         hands.sort();
 
         assert_eq!(
             hands,
             vec![
-                Hand::from_str("32T3K 1"),
-                Hand::from_str("KK677 3"),
-                Hand::from_str("T55J5 2"),
-                Hand::from_str("QQQJA 5"),
-                Hand::from_str("KTJJT 4"),
+                Hand::from_str("32T3K 1").unwrap(),
+                Hand::from_str("KK677 3").unwrap(),
+                Hand::from_str("T55J5 2").unwrap(),
+                Hand::from_str("QQQJA 5").unwrap(),
+                Hand::from_str("KTJJT 4").unwrap(),
             ]
         );
     }
 
     #[test]
     fn test_hand_types() {
-        assert_eq!(Hand::from_str("32T3K 1").hand_type(), HandType::OnePair);
-        assert_eq!(Hand::from_str("KK677 1").hand_type(), HandType::TwoPair);
-        assert_eq!(Hand::from_str("T55J5 1").hand_type(), HandType::FourOfAKind);
-        assert_eq!(Hand::from_str("KTJJT 1").hand_type(), HandType::FourOfAKind);
-        assert_eq!(Hand::from_str("QQQJA 1").hand_type(), HandType::FourOfAKind);
+        assert_eq!(
+            Hand::from_str("32T3K 1").unwrap().hand_type(),
+            HandType::OnePair
+        );
+        assert_eq!(
+            Hand::from_str("KK677 1").unwrap().hand_type(),
+            HandType::TwoPair
+        );
+        assert_eq!(
+            Hand::from_str("T55J5 1").unwrap().hand_type(),
+            HandType::FourOfAKind
+        );
+        assert_eq!(
+            Hand::from_str("KTJJT 1").unwrap().hand_type(),
+            HandType::FourOfAKind
+        );
+        assert_eq!(
+            Hand::from_str("QQQJA 1").unwrap().hand_type(),
+            HandType::FourOfAKind
+        );
 
-        assert_eq!(Hand::from_str("55555 1").hand_type(), HandType::FiveOfAKind);
-        assert_eq!(Hand::from_str("55J55 1").hand_type(), HandType::FiveOfAKind);
-        assert_eq!(Hand::from_str("555JJ 1").hand_type(), HandType::FiveOfAKind);
-        assert_eq!(Hand::from_str("JJJJJ 1").hand_type(), HandType::FiveOfAKind);
-        assert_eq!(Hand::from_str("JJJJ1 1").hand_type(), HandType::FiveOfAKind);
+        assert_eq!(
+            Hand::from_str("55555 1").unwrap().hand_type(),
+            HandType::FiveOfAKind
+        );
+        assert_eq!(
+            Hand::from_str("55J55 1").unwrap().hand_type(),
+            HandType::FiveOfAKind
+        );
+        assert_eq!(
+            Hand::from_str("555JJ 1").unwrap().hand_type(),
+            HandType::FiveOfAKind
+        );
+        assert_eq!(
+            Hand::from_str("JJJJJ 1").unwrap().hand_type(),
+            HandType::FiveOfAKind
+        );
+        assert_eq!(
+            Hand::from_str("JJJJ2 1").unwrap().hand_type(),
+            HandType::FiveOfAKind
+        );
 
-        assert_eq!(Hand::from_str("55551 1").hand_type(), HandType::FourOfAKind);
-        assert_eq!(Hand::from_str("55J51 1").hand_type(), HandType::FourOfAKind);
-        assert_eq!(Hand::from_str("555J1 1").hand_type(), HandType::FourOfAKind);
+        assert_eq!(
+            Hand::from_str("55552 1").unwrap().hand_type(),
+            HandType::FourOfAKind
+        );
+        assert_eq!(
+            Hand::from_str("55J52 1").unwrap().hand_type(),
+            HandType::FourOfAKind
+        );
+        assert_eq!(
+            Hand::from_str("555J2 1").unwrap().hand_type(),
+            HandType::FourOfAKind
+        );
 
         assert_eq!(
-            Hand::from_str("12333 1").hand_type(),
+            Hand::from_str("42333 1").unwrap().hand_type(),
             HandType::ThreeOfAKind
         );
         assert_eq!(
-            Hand::from_str("12J33 1").hand_type(),
+            Hand::from_str("42J33 1").unwrap().hand_type(),
             HandType::ThreeOfAKind
         );
-        assert_eq!(Hand::from_str("333AA 1").hand_type(), HandType::FullHouse);
-        assert_eq!(Hand::from_str("A33AA 1").hand_type(), HandType::FullHouse);
         assert_eq!(
-            Hand::from_str("33JAA 1").hand_type(),
+            Hand::from_str("333AA 1").unwrap().hand_type(),
+            HandType::FullHouse
+        );
+        assert_eq!(
+            Hand::from_str("A33AA 1").unwrap().hand_type(),
+            HandType::FullHouse
+        );
+        assert_eq!(
+            Hand::from_str("33JAA 1").unwrap().hand_type(),
             HandType::FullHouse // 3 3s, 2 As
         );
         assert_eq!(
-            Hand::from_str("3JJAA 1").hand_type(),
+            Hand::from_str("3JJAA 1").unwrap().hand_type(),
             HandType::FourOfAKind // should snap to being the 'A'
         );
-        assert_eq!(Hand::from_str("JJJAA 1").hand_type(), HandType::FiveOfAKind);
+        assert_eq!(
+            Hand::from_str("JJJAA 1").unwrap().hand_type(),
+            HandType::FiveOfAKind
+        );
     }
 }
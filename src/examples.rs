@@ -0,0 +1,184 @@
+// pulls the worked examples out of a saved puzzle page, so new days start with example tests
+use crate::html::{inner_blocks, text};
+use crate::parse::offset_in;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Example {
+    pub part: u8,
+    pub input: String,
+    // the emphasised answer the example works towards, i.e. "142"
+    pub answer: Option<String>,
+}
+
+// one <article class="day-desc"> per part, part 2 only shows up once part 1 is solved
+// a part can have several examples, i.e. a second example input further down the article
+pub fn extract_examples(html: &str) -> Vec<Example> {
+    let mut examples: Vec<Example> = Vec::new();
+
+    for (idx, article) in inner_blocks(html, "article").into_iter().enumerate() {
+        let part = idx as u8 + 1;
+        let pre_blocks = inner_blocks(article, "pre");
+
+        // answers are emphasised code, i.e. "adding these together produces <code><em>142</em></code>."
+        let answers: Vec<&str> = inner_blocks(article, "code")
+            .into_iter()
+            .filter(|code| code.starts_with("<em>") && code.ends_with("</em>"))
+            .collect();
+
+        if pre_blocks.is_empty() {
+            // part 2 usually reuses part 1's example rather than showing it again
+            if let Some(previous) = examples.last() {
+                examples.push(Example {
+                    part,
+                    input: previous.input.clone(),
+                    answer: answers.last().map(|answer| text(answer)),
+                });
+            }
+            continue;
+        }
+
+        // each answer goes with the first <pre> since the previous answer, later blocks
+        // before the answer are worked steps of that same example, not new inputs
+        let mut unpaired: Option<&str> = None;
+        let mut pre_blocks = pre_blocks.into_iter().peekable();
+        let mut part_examples: Vec<Example> = Vec::new();
+        for answer in &answers {
+            while let Some(pre) =
+                pre_blocks.next_if(|pre| offset_in(article, pre) < offset_in(article, answer))
+            {
+                unpaired.get_or_insert(pre);
+            }
+            if let Some(input) = unpaired.take() {
+                part_examples.push(example(part, input, Some(text(answer))));
+            } else if let Some(last) = part_examples.last_mut() {
+                // a later answer for the same example wins, i.e. "... so the total is <em>142</em>"
+                last.answer = Some(text(answer));
+            }
+        }
+
+        // an example without an emphasised answer
+        if part_examples.is_empty() {
+            let input = unpaired.or_else(|| pre_blocks.next()).unwrap();
+            part_examples.push(example(part, input, None));
+        }
+
+        examples.extend(part_examples);
+    }
+
+    examples
+}
+
+fn example(part: u8, input: &str, answer: Option<String>) -> Example {
+    Example {
+        part,
+        input: text(input).trim_end_matches('\n').to_string(),
+        answer,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // trimmed down 2023 day 1
+    const PUZZLE_HTML: &str = r#"<!DOCTYPE html>
+<html lang="en-us">
+<body>
+<main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2><p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+</code></pre>
+<p>In this example, the calibration values of these four lines are <code>12</code>, <code>38</code>, <code>15</code>, and <code>77</code>. Adding these together produces <code><em>142</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>55621</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Equipped with this new information, you now need to find the real first and last digit on each line. For example:</p>
+<pre><code>two1nine
+eightwothree
+abc&lt;1&gt;
+</code></pre>
+<p>Adding these together produces <code><em>281</em></code>.</p>
+</article>
+</main>
+</body>
+</html>"#;
+
+    #[test]
+    fn test_extract_examples() {
+        let examples = extract_examples(PUZZLE_HTML);
+
+        assert_eq!(
+            examples,
+            vec![
+                Example {
+                    part: 1,
+                    input: "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet".to_string(),
+                    answer: Some("142".to_string()),
+                },
+                Example {
+                    part: 2,
+                    input: "two1nine\neightwothree\nabc<1>".to_string(),
+                    answer: Some("281".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_part2_reuses_example() {
+        let html = "
+<article><pre><code>1
+2
+</code></pre><p>The answer is <code><em>3</em></code>.</p></article>
+<article><p>Multiply instead, giving <code><em>2</em></code>.</p></article>";
+
+        let examples = extract_examples(html);
+
+        assert_eq!(examples[1].input, "1\n2");
+        assert_eq!(examples[1].answer, Some("2".to_string()));
+    }
+
+    #[test]
+    fn test_several_examples_in_one_part() {
+        // a worked step between the input & its answer, then a second, larger example
+        let html = "
+<article><pre><code>1
+2
+</code></pre><p>Step one:</p><pre><code>3
+</code></pre><p>So the answer is <code>3</code> then <code><em>6</em></code>.</p>
+<p>A larger example:</p><pre><code>10
+20
+</code></pre><p>This one gives <code><em>60</em></code>.</p></article>
+<article><p>Now multiply, giving <code><em>2</em></code>.</p></article>";
+
+        let examples = extract_examples(html);
+
+        assert_eq!(
+            examples
+                .iter()
+                .map(|example| (
+                    example.part,
+                    example.input.as_str(),
+                    example.answer.as_deref()
+                ))
+                .collect::<Vec<(u8, &str, Option<&str>)>>(),
+            vec![
+                (1, "1\n2", Some("6")),
+                (1, "10\n20", Some("60")),
+                // part 2 reuses the last example shown
+                (2, "10\n20", Some("2")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_no_examples() {
+        assert!(extract_examples("<html><body>404</body></html>").is_empty());
+
+        // an example without an emphasised answer
+        let examples = extract_examples("<article><pre><code>1</code></pre></article>");
+        assert_eq!(examples[0].answer, None);
+    }
+}
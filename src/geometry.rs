@@ -0,0 +1,343 @@
+// points, offsets & compass directions on a 2D map
+// y grows downwards, the way puzzle inputs are drawn, so north is y - 1
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy, Default)]
+pub struct Point2 {
+    pub x: i32,
+    pub y: i32,
+}
+
+// the difference between two points
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy, Default)]
+pub struct Vec2 {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2 { x: 0, y: 0 };
+
+    pub const fn new(x: i32, y: i32) -> Point2 {
+        Point2 { x, y }
+    }
+
+    // steps on a grid without diagonals
+    pub fn manhattan_distance(self, other: Point2) -> u32 {
+        (other - self).manhattan_length()
+    }
+
+    // steps on a grid with diagonals
+    pub fn chebyshev_distance(self, other: Point2) -> u32 {
+        (other - self).chebyshev_length()
+    }
+
+    // clockwise from north
+    pub fn neighbours4(self) -> impl Iterator<Item = Point2> {
+        Direction4::ALL
+            .into_iter()
+            .map(move |direction| self + direction)
+    }
+
+    // clockwise from north, including diagonals
+    pub fn neighbours8(self) -> impl Iterator<Item = Point2> {
+        Direction8::ALL
+            .into_iter()
+            .map(move |direction| self + direction)
+    }
+}
+
+impl Vec2 {
+    pub const fn new(x: i32, y: i32) -> Vec2 {
+        Vec2 { x, y }
+    }
+
+    pub fn manhattan_length(self) -> u32 {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+
+    pub fn chebyshev_length(self) -> u32 {
+        self.x.unsigned_abs().max(self.y.unsigned_abs())
+    }
+
+    // a quarter turn, north -> east
+    pub fn rotate_cw(self) -> Vec2 {
+        Vec2::new(-self.y, self.x)
+    }
+
+    // a quarter turn, north -> west
+    pub fn rotate_ccw(self) -> Vec2 {
+        Vec2::new(self.y, -self.x)
+    }
+}
+
+impl Add<Vec2> for Point2 {
+    type Output = Point2;
+
+    fn add(self, rhs: Vec2) -> Point2 {
+        Point2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign<Vec2> for Point2 {
+    fn add_assign(&mut self, rhs: Vec2) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Vec2> for Point2 {
+    type Output = Point2;
+
+    fn sub(self, rhs: Vec2) -> Point2 {
+        Point2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign<Vec2> for Point2 {
+    fn sub_assign(&mut self, rhs: Vec2) {
+        *self = *self - rhs;
+    }
+}
+
+impl Sub for Point2 {
+    type Output = Vec2;
+
+    fn sub(self, rhs: Point2) -> Vec2 {
+        Vec2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+// a step in a direction, i.e. position + Direction4::North
+impl Add<Direction4> for Point2 {
+    type Output = Point2;
+
+    fn add(self, rhs: Direction4) -> Point2 {
+        self + rhs.vec()
+    }
+}
+
+impl Add<Direction8> for Point2 {
+    type Output = Point2;
+
+    fn add(self, rhs: Direction8) -> Point2 {
+        self + rhs.vec()
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, rhs: Vec2) -> Vec2 {
+        Vec2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, rhs: Vec2) -> Vec2 {
+        Vec2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Vec2 {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i32> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, rhs: i32) -> Vec2 {
+        Vec2::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Direction4 {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction4 {
+    // clockwise from north
+    pub const ALL: [Direction4; 4] = [
+        Direction4::North,
+        Direction4::East,
+        Direction4::South,
+        Direction4::West,
+    ];
+
+    pub fn vec(self) -> Vec2 {
+        match self {
+            Direction4::North => Vec2::new(0, -1),
+            Direction4::East => Vec2::new(1, 0),
+            Direction4::South => Vec2::new(0, 1),
+            Direction4::West => Vec2::new(-1, 0),
+        }
+    }
+
+    pub fn rotate_cw(self) -> Direction4 {
+        Direction4::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn rotate_ccw(self) -> Direction4 {
+        Direction4::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Direction4 {
+        Direction4::ALL[(self as usize + 2) % 4]
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    // clockwise from north
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    pub fn vec(self) -> Vec2 {
+        match self {
+            Direction8::North => Vec2::new(0, -1),
+            Direction8::NorthEast => Vec2::new(1, -1),
+            Direction8::East => Vec2::new(1, 0),
+            Direction8::SouthEast => Vec2::new(1, 1),
+            Direction8::South => Vec2::new(0, 1),
+            Direction8::SouthWest => Vec2::new(-1, 1),
+            Direction8::West => Vec2::new(-1, 0),
+            Direction8::NorthWest => Vec2::new(-1, -1),
+        }
+    }
+
+    // an eighth of a turn
+    pub fn rotate_cw(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn rotate_ccw(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Direction8 {
+        Direction8::ALL[direction as usize * 2]
+    }
+}
+
+impl From<Direction4> for Vec2 {
+    fn from(direction: Direction4) -> Vec2 {
+        direction.vec()
+    }
+}
+
+impl From<Direction8> for Vec2 {
+    fn from(direction: Direction8) -> Vec2 {
+        direction.vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let point = Point2::new(2, 3);
+
+        assert_eq!(point + Vec2::new(-1, 1), Point2::new(1, 4));
+        assert_eq!(point - Vec2::new(2, 3), Point2::ORIGIN);
+        assert_eq!(Point2::new(5, 1) - point, Vec2::new(3, -2));
+        assert_eq!(Vec2::new(1, -2) * 3, Vec2::new(3, -6));
+        assert_eq!(-Vec2::new(1, -2), Vec2::new(-1, 2));
+
+        let mut moved = point;
+        moved += Direction4::North.vec();
+        moved -= Vec2::new(1, 0);
+        assert_eq!(moved, Point2::new(1, 2));
+
+        // chained steps
+        let moved = Point2::ORIGIN + Direction4::North + Direction4::East + Direction4::East;
+        assert_eq!(moved + Direction4::South, Point2::new(2, 0));
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point2::new(1, 1);
+        let b = Point2::new(-2, 5);
+
+        assert_eq!(a.manhattan_distance(b), 7);
+        assert_eq!(b.manhattan_distance(a), 7);
+        assert_eq!(a.chebyshev_distance(b), 4);
+        assert_eq!(a.chebyshev_distance(a), 0);
+    }
+
+    #[test]
+    fn test_rotation() {
+        assert_eq!(Direction4::North.vec().rotate_cw(), Direction4::East.vec());
+        assert_eq!(Direction4::West.vec().rotate_ccw(), Direction4::South.vec());
+        assert_eq!(Direction4::West.rotate_cw(), Direction4::North);
+        assert_eq!(Direction4::North.rotate_ccw(), Direction4::West);
+        assert_eq!(Direction4::East.opposite(), Direction4::West);
+
+        assert_eq!(Direction8::NorthWest.rotate_cw(), Direction8::North);
+        assert_eq!(Direction8::North.rotate_ccw(), Direction8::NorthWest);
+        assert_eq!(Direction8::SouthEast.opposite(), Direction8::NorthWest);
+        assert_eq!(Direction8::from(Direction4::South), Direction8::South);
+
+        // every direction turns the same way as its vector
+        for direction in Direction8::ALL {
+            assert_eq!(direction.opposite().vec(), -direction.vec());
+        }
+        for direction in Direction4::ALL {
+            assert_eq!(direction.rotate_cw().vec(), direction.vec().rotate_cw());
+        }
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let point = Point2::new(0, 0);
+
+        assert_eq!(
+            point.neighbours4().collect::<Vec<Point2>>(),
+            vec![
+                Point2::new(0, -1),
+                Point2::new(1, 0),
+                Point2::new(0, 1),
+                Point2::new(-1, 0)
+            ]
+        );
+        assert_eq!(point.neighbours8().count(), 8);
+        assert!(point
+            .neighbours8()
+            .all(|neighbour| point.chebyshev_distance(neighbour) == 1));
+    }
+}
@@ -0,0 +1,286 @@
+// a rectangular map of cells, for the puzzles that hand over a picture of characters
+// the top left cell is (0, 0)
+use crate::geometry::Point2;
+use crate::parse::{all_lines, LineParser, ParseError};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    // row by row
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    // one character per cell, `cell` returns None for characters the grid doesn't know about
+    // only the newlines at the end are dropped, a blank line within the grid is an error
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        let rows = input.trim_end_matches(['\r', '\n']);
+        if rows.is_empty() {
+            return Err(ParseError::end_of_input(input, "a grid row"));
+        }
+
+        for line in all_lines(rows) {
+            let parser = LineParser::new(line.text);
+            let row_width = line.text.chars().count();
+            if row_width == 0 {
+                return Err(parser.error("", "a grid row").with_line(line.number));
+            }
+            if *width.get_or_insert(row_width) != row_width {
+                let expected = format!("a row {} cells wide", width.unwrap_or_default());
+                return Err(parser.error(line.text, &expected).with_line(line.number));
+            }
+
+            for (idx, char) in line.text.char_indices() {
+                let value = cell(char).ok_or_else(|| {
+                    parser
+                        .error(&line.text[idx..idx + char.len_utf8()], "a grid cell")
+                        .with_line(line.number)
+                })?;
+                cells.push(value);
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or_default(),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, position: Point2) -> bool {
+        (0..self.width as i32).contains(&position.x)
+            && (0..self.height as i32).contains(&position.y)
+    }
+
+    pub fn get(&self, position: Point2) -> Option<&T> {
+        let index = self.index(position)?;
+
+        Some(&self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: Point2) -> Option<&mut T> {
+        let index = self.index(position)?;
+
+        Some(&mut self.cells[index])
+    }
+
+    fn index(&self, position: Point2) -> Option<usize> {
+        self.in_bounds(position)
+            .then(|| position.y as usize * self.width + position.x as usize)
+    }
+
+    // clockwise from north, skipping any off the edge
+    pub fn neighbours4(&self, position: Point2) -> impl Iterator<Item = Point2> + '_ {
+        position
+            .neighbours4()
+            .filter(|neighbour| self.in_bounds(*neighbour))
+    }
+
+    // including diagonals
+    pub fn neighbours8(&self, position: Point2) -> impl Iterator<Item = Point2> + '_ {
+        position
+            .neighbours8()
+            .filter(|neighbour| self.in_bounds(*neighbour))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on 0, an empty grid has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        (0..self.height).filter_map(move |y| self.get(Point2::new(x as i32, y as i32)))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    // every cell with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Point2, &T)> {
+        self.cells.iter().enumerate().map(|(idx, cell)| {
+            let position = Point2::new((idx % self.width) as i32, (idx / self.width) as i32);
+            (position, cell)
+        })
+    }
+
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point2> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    pub fn find_all<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Point2> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Grid::parse(input, Some)
+    }
+}
+
+// a line per row, the way the puzzle draws it
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<char> {
+        "
+abc
+def
+        "
+        .trim()
+        .parse()
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = example();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(Point2::new(2, 1)), Some(&'f'));
+        assert_eq!(grid.get(Point2::new(3, 1)), None);
+        assert_eq!(grid.get(Point2::new(0, 2)), None);
+        assert_eq!(grid.get(Point2::new(-1, 0)), None);
+        assert_eq!(grid.to_string(), "abc\ndef");
+
+        let digits = Grid::parse("12\r\n34\r\n", |char| char.to_digit(10)).unwrap();
+        assert_eq!(digits.rows().collect::<Vec<&[u32]>>(), vec![[1, 2], [3, 4]]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = "abc\nde\n".parse::<Grid<char>>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected a row 3 cells wide, found \"de\""
+        );
+
+        let err = Grid::parse("12\n3x", |char| char.to_digit(10)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 2: expected a grid cell, found \"x\""
+        );
+
+        // a blank line would shift every row after it
+        let err = "abc\n\ndef\n".parse::<Grid<char>>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected a grid row, found the end of the line"
+        );
+        let err = "\r\nabc\r\n".parse::<Grid<char>>().unwrap_err();
+        assert_eq!(err.line, Some(1));
+
+        assert_eq!(
+            "\n\n".parse::<Grid<char>>().unwrap_err().to_string(),
+            "line 3, column 1: expected a grid row, found the end of the input"
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = example();
+
+        // corners don't wrap or underflow
+        assert_eq!(
+            grid.neighbours4(Point2::ORIGIN).collect::<Vec<Point2>>(),
+            vec![Point2::new(1, 0), Point2::new(0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours8(Point2::new(2, 1)).collect::<Vec<Point2>>(),
+            vec![Point2::new(2, 0), Point2::new(1, 1), Point2::new(1, 0)]
+        );
+        assert_eq!(grid.neighbours8(Point2::new(1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = example();
+
+        assert_eq!(
+            grid.columns()
+                .map(|column| column.collect::<String>())
+                .collect::<Vec<String>>(),
+            vec!["ad", "be", "cf"]
+        );
+        assert_eq!(grid.column(3).count(), 0);
+    }
+
+    #[test]
+    fn test_find() {
+        let mut grid = example();
+
+        assert_eq!(grid.find(|cell| *cell == 'e'), Some(Point2::new(1, 1)));
+        assert_eq!(grid.find(|cell| *cell == 'z'), None);
+
+        *grid.get_mut(Point2::new(0, 1)).unwrap() = 'a';
+        assert_eq!(
+            grid.find_all(|cell| *cell == 'a').collect::<Vec<Point2>>(),
+            vec![Point2::new(0, 0), Point2::new(0, 1)]
+        );
+    }
+
+    #[test]
+    fn test_new() {
+        let mut grid = Grid::new(2, 2, '.');
+        *grid.get_mut(Point2::new(1, 0)).unwrap() = '#';
+
+        assert_eq!(grid.to_string(), ".#\n..");
+        assert_eq!(grid.get_mut(Point2::new(2, 0)), None);
+    }
+}
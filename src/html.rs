@@ -0,0 +1,92 @@
+// just enough html handling for adventofcode.com's puzzle pages, which are simple & regular
+// tags are matched by name only & assumed not to nest inside themselves
+
+// contents of every <tag ...>...</tag>, in order
+pub fn inner_blocks<'a>(html: &'a str, tag: &str) -> Vec<&'a str> {
+    let open = format!("<{tag}");
+    let close = format!("</{tag}>");
+
+    let mut blocks = Vec::new();
+    let mut rest = html;
+    while let Some(start) = rest.find(&open) {
+        let after_name = &rest[start + open.len()..];
+
+        // <pre> or <pre class="..."> but not <preview>
+        if !after_name.starts_with(['>', ' ', '\n', '\t']) {
+            rest = after_name;
+            continue;
+        }
+        let Some(tag_end) = after_name.find('>') else {
+            break;
+        };
+        let inner = &after_name[tag_end + 1..];
+        let Some(end) = inner.find(&close) else {
+            break;
+        };
+
+        blocks.push(&inner[..end]);
+        rest = &inner[end + close.len()..];
+    }
+
+    blocks
+}
+
+pub fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+
+    for char in html.chars() {
+        match char {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(char),
+            _ => {}
+        }
+    }
+
+    text
+}
+
+pub fn unescape(text: &str) -> String {
+    // &amp; last, so "&amp;lt;" stays "&lt;"
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&amp;", "&")
+}
+
+// visible text of some html, i.e. "<em>1</em> &lt; 2" -> "1 < 2"
+pub fn text(html: &str) -> String {
+    unescape(&strip_tags(html))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inner_blocks() {
+        let html = r#"<article class="day-desc"><p>a</p><pre><code>1
+2</code></pre><preview>no</preview><pre>3</pre></article>"#;
+
+        assert_eq!(inner_blocks(html, "article").len(), 1);
+        assert_eq!(inner_blocks(html, "pre"), vec!["<code>1\n2</code>", "3"]);
+        assert_eq!(inner_blocks(html, "code"), vec!["1\n2"]);
+        assert!(inner_blocks(html, "table").is_empty());
+
+        // unclosed tags are ignored
+        assert!(inner_blocks("<pre>1", "pre").is_empty());
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!(text("<code><em>142</em></code>"), "142");
+        assert_eq!(
+            text("a &lt;b&gt; &amp;lt; &quot;c&quot;"),
+            "a <b> &lt; \"c\""
+        );
+        assert_eq!(strip_tags("1 > 0 <em>yes</em>"), "1 > 0 yes");
+    }
+}
@@ -0,0 +1,183 @@
+use crate::parse::numbered_lines;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// the year this repo is named after, its inputs don't get a year prefix
+pub const CURRENT_YEAR: u16 = 2023;
+
+// overrides where inputs are read from, the --inputs flag takes priority
+pub const INPUTS_DIR_ENV: &str = "AOC_INPUTS_DIR";
+// picks a named input set (a sub directory of the inputs dir), the --input-set flag takes priority
+pub const INPUT_SET_ENV: &str = "AOC_INPUT_SET";
+
+pub fn input_file_name(year: u16, day: u8) -> String {
+    // 2023 -> "01.txt"
+    // older years are prefixed with the year -> "2022_04.txt"
+    if year == CURRENT_YEAR {
+        format!("{day:02}.txt")
+    } else {
+        format!("{year}_{day:02}.txt")
+    }
+}
+
+// where puzzle inputs are read from
+// inputs/01.txt for the default set, inputs/<set>/01.txt for a teammate's inputs
+#[derive(Debug, PartialEq, Clone)]
+pub struct InputSource {
+    pub root: PathBuf,
+    pub set: Option<String>,
+}
+
+impl InputSource {
+    pub fn new(root: impl Into<PathBuf>, set: Option<String>) -> InputSource {
+        InputSource {
+            root: root.into(),
+            set,
+        }
+    }
+
+    // flags win over env vars, which win over the repo's inputs dir
+    // absolute default, so it works no matter where it's run from
+    pub fn resolve(root: Option<PathBuf>, set: Option<String>) -> InputSource {
+        let root = root
+            .or_else(|| std::env::var_os(INPUTS_DIR_ENV).map(PathBuf::from))
+            .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs"));
+        let set = set.or_else(|| std::env::var(INPUT_SET_ENV).ok());
+
+        InputSource::new(root, set)
+    }
+
+    pub fn dir(&self) -> PathBuf {
+        match &self.set {
+            Some(set) => self.root.join(set),
+            None => self.root.clone(),
+        }
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir().join(input_file_name(year, day))
+    }
+
+    pub fn load(&self, year: u16, day: u8) -> Result<String, InputError> {
+        let path = self.path(year, day);
+
+        fs::read_to_string(&path).map_err(|source| InputError { path, source })
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub path: PathBuf,
+    pub source: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Failed to read input file {}: {}",
+            self.path.display(),
+            self.source
+        )
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+// inputs from the env / default location, panics with the missing path
+// for tests & benches, the runner uses InputSource directly
+pub fn load_input(year: u16, day: u8) -> String {
+    InputSource::resolve(None, None)
+        .load(year, day)
+        .unwrap_or_else(|err| panic!("{err}"))
+}
+
+// non-blank lines without their line endings, see crate::parse for numbered lines & records
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    numbered_lines(input).map(|line| line.text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::temp_dir::TempDir;
+
+    #[test]
+    fn test_input_file_name() {
+        assert_eq!(input_file_name(2023, 1), "01.txt");
+        assert_eq!(input_file_name(2023, 12), "12.txt");
+        assert_eq!(input_file_name(2021, 1), "2021_01.txt");
+        assert_eq!(input_file_name(2022, 23), "2022_23.txt");
+    }
+
+    #[test]
+    fn test_input_source_path() {
+        let source = InputSource::new("/tmp/aoc", None);
+        assert_eq!(source.path(2023, 5), PathBuf::from("/tmp/aoc/05.txt"));
+        assert_eq!(source.path(2022, 4), PathBuf::from("/tmp/aoc/2022_04.txt"));
+
+        let source = InputSource::new("/tmp/aoc", Some("alice".to_string()));
+        assert_eq!(source.path(2023, 5), PathBuf::from("/tmp/aoc/alice/05.txt"));
+    }
+
+    #[test]
+    fn test_resolve_flags() {
+        // flags are used over env vars & the default
+        let source = InputSource::resolve(Some("/tmp/aoc".into()), Some("bob".to_string()));
+        assert_eq!(
+            source,
+            InputSource::new("/tmp/aoc", Some("bob".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_load_named_set() {
+        let root = TempDir::new("inputs");
+        fs::create_dir_all(root.join("alice")).unwrap();
+        fs::write(root.join("alice").join("01.txt"), "alice's input").unwrap();
+
+        let alice = InputSource::new(root.path(), Some("alice".to_string()));
+        assert_eq!(alice.load(2023, 1).unwrap(), "alice's input");
+
+        // missing files name the full path
+        let bob = InputSource::new(root.path(), Some("bob".to_string()));
+        let err = bob.load(2023, 1).unwrap_err();
+        assert_eq!(err.path, root.join("bob").join("01.txt"));
+        assert!(err.to_string().contains(&format!("{}", err.path.display())));
+        assert_eq!(err.source.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_load_input() {
+        assert!(load_input(2023, 1).starts_with(|c: char| c.is_ascii_alphanumeric()));
+    }
+
+    #[test]
+    #[should_panic(expected = "inputs/1999_01.txt")]
+    fn test_load_missing_input() {
+        load_input(1999, 1);
+    }
+
+    #[test]
+    fn test_lines() {
+        let example_input = "
+1abc2
+pqr3stu8vwx
+
+treb7uchet
+";
+
+        assert_eq!(
+            lines(example_input).collect::<Vec<&str>>(),
+            vec!["1abc2", "pqr3stu8vwx", "treb7uchet"]
+        );
+        assert_eq!(lines("").count(), 0);
+        assert_eq!(lines("a\r\nb\r\n").collect::<Vec<&str>>(), vec!["a", "b"]);
+    }
+}
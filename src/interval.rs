@@ -0,0 +1,392 @@
+// ranges of whole numbers, i.e. section assignments or almanac seed ranges
+// both ends are inclusive, the way the puzzles write them ("2-4" is 2, 3 & 4)
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy)]
+pub struct Interval {
+    start: i64,
+    end: i64,
+}
+
+impl Interval {
+    pub fn new(start: i64, end: i64) -> Interval {
+        assert!(start <= end, "empty interval {start}-{end}");
+
+        Interval { start, end }
+    }
+
+    // "79 14" style, a start & how many values follow it
+    // None for no values, or when the end would be past i64::MAX
+    pub fn with_length(start: i64, length: i64) -> Option<Interval> {
+        if length < 1 {
+            return None;
+        }
+
+        Some(Interval::new(start, start.checked_add(length - 1)?))
+    }
+
+    pub fn start(&self) -> i64 {
+        self.start
+    }
+
+    pub fn end(&self) -> i64 {
+        self.end
+    }
+
+    // how many values it holds, never 0
+    // None when that's more than an i64 can count, i.e. i64::MIN to -1
+    pub fn length(&self) -> Option<i64> {
+        self.end.checked_sub(self.start)?.checked_add(1)
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    // a: ......XXXXXX......
+    // b: ........XXXX......
+    pub fn contains_interval(&self, other: &Interval) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    // a: ......XXXXXX......
+    // b: ........XXXXX.....
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    // the values in both
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        self.overlaps(other)
+            .then(|| Interval::new(self.start.max(other.start), self.end.min(other.end)))
+    }
+
+    // the values in self but not in other, up to 2 pieces either side of it
+    pub fn difference(&self, other: &Interval) -> Vec<Interval> {
+        if !self.overlaps(other) {
+            return vec![*self];
+        }
+
+        let (below, rest) = self.split_at(other.start);
+        // nothing can be above an other that goes all the way to i64::MAX
+        let above = rest
+            .zip(other.end.checked_add(1))
+            .and_then(|(rest, after)| rest.split_at(after).1);
+
+        below.into_iter().chain(above).collect()
+    }
+
+    // one interval covering both, if they overlap or touch
+    pub fn union(&self, other: &Interval) -> Option<Interval> {
+        let touching =
+            self.start <= other.end.saturating_add(1) && other.start <= self.end.saturating_add(1);
+
+        touching.then(|| Interval::new(self.start.min(other.start), self.end.max(other.end)))
+    }
+
+    // (everything below value, everything from value up)
+    pub fn split_at(&self, value: i64) -> (Option<Interval>, Option<Interval>) {
+        if value <= self.start {
+            (None, Some(*self))
+        } else if value > self.end {
+            (Some(*self), None)
+        } else {
+            (
+                Some(Interval::new(self.start, value - 1)),
+                Some(Interval::new(value, self.end)),
+            )
+        }
+    }
+
+    // None if either end would be moved past i64::MIN or i64::MAX
+    pub fn shift(&self, delta: i64) -> Option<Interval> {
+        Some(Interval::new(
+            self.start.checked_add(delta)?,
+            self.end.checked_add(delta)?,
+        ))
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+// any number of values, kept as sorted intervals that don't overlap or touch
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> IntervalSet {
+        IntervalSet::default()
+    }
+
+    // adds the values, merging it with any intervals it overlaps or touches
+    pub fn insert(&mut self, interval: Interval) {
+        // everything before first_touching ends too early to merge, everything from
+        // after_touching starts too late
+        let first_touching = self
+            .intervals
+            .partition_point(|existing| existing.end.saturating_add(1) < interval.start);
+        let after_touching = self
+            .intervals
+            .partition_point(|existing| existing.start <= interval.end.saturating_add(1));
+
+        let merged = self.intervals[first_touching..after_touching]
+            .iter()
+            .fold(interval, |merged, existing| merged.union(existing).unwrap());
+        self.intervals
+            .splice(first_touching..after_touching, [merged]);
+    }
+
+    // removes the values, splitting any interval it lands in the middle of
+    pub fn remove(&mut self, interval: &Interval) {
+        self.intervals = self
+            .intervals
+            .iter()
+            .flat_map(|existing| existing.difference(interval))
+            .collect();
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let idx = self
+            .intervals
+            .partition_point(|interval| interval.end < value);
+
+        self.intervals
+            .get(idx)
+            .is_some_and(|interval| interval.contains(value))
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        self.iter().chain(other.iter()).copied().collect()
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        self.iter()
+            .flat_map(|a| other.iter().filter_map(|b| a.intersection(b)))
+            .collect()
+    }
+
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut difference = self.clone();
+        for interval in other.iter() {
+            difference.remove(interval);
+        }
+
+        difference
+    }
+
+    // (everything below value, everything from value up)
+    pub fn split_at(&self, value: i64) -> (IntervalSet, IntervalSet) {
+        let mut below = IntervalSet::new();
+        let mut above = IntervalSet::new();
+
+        for interval in self.iter() {
+            let (interval_below, interval_above) = interval.split_at(value);
+            below.intervals.extend(interval_below);
+            above.intervals.extend(interval_above);
+        }
+
+        (below, above)
+    }
+
+    // the lowest value in the set
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.intervals.last().map(|interval| interval.end)
+    }
+
+    // how many values it holds, None when that's more than an i64 can count
+    pub fn total_length(&self) -> Option<i64> {
+        self.iter().try_fold(0i64, |total, interval| {
+            total.checked_add(interval.length()?)
+        })
+    }
+
+    // number of separate intervals
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    // lowest first
+    pub fn iter(&self) -> impl Iterator<Item = &Interval> {
+        self.intervals.iter()
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(intervals: I) -> IntervalSet {
+        let mut set = IntervalSet::new();
+        for interval in intervals {
+            set.insert(interval);
+        }
+
+        set
+    }
+}
+
+impl From<Interval> for IntervalSet {
+    fn from(interval: Interval) -> IntervalSet {
+        IntervalSet {
+            intervals: vec![interval],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet {
+        intervals
+            .iter()
+            .map(|(start, end)| Interval::new(*start, *end))
+            .collect()
+    }
+
+    #[test]
+    fn test_contains_and_overlaps() {
+        let interval = Interval::new(2, 6);
+
+        assert!(interval.contains(2) && interval.contains(6));
+        assert!(!interval.contains(1) && !interval.contains(7));
+        assert_eq!(interval.length(), Some(5));
+        assert_eq!(Interval::with_length(79, 14), Some(Interval::new(79, 92)));
+        assert_eq!(Interval::with_length(79, 0), None);
+
+        assert!(interval.contains_interval(&Interval::new(3, 6)));
+        assert!(!interval.contains_interval(&Interval::new(3, 7)));
+
+        assert!(interval.overlaps(&Interval::new(6, 8)));
+        assert!(Interval::new(6, 8).overlaps(&interval));
+        assert!(!interval.overlaps(&Interval::new(7, 8)));
+    }
+
+    #[test]
+    fn test_intersection_and_difference() {
+        let interval = Interval::new(2, 8);
+
+        assert_eq!(
+            interval.intersection(&Interval::new(5, 10)),
+            Some(Interval::new(5, 8))
+        );
+        assert_eq!(interval.intersection(&Interval::new(9, 10)), None);
+
+        assert_eq!(
+            interval.difference(&Interval::new(4, 5)),
+            vec![Interval::new(2, 3), Interval::new(6, 8)]
+        );
+        assert_eq!(
+            interval.difference(&Interval::new(0, 5)),
+            vec![Interval::new(6, 8)]
+        );
+        assert_eq!(interval.difference(&Interval::new(0, 10)), vec![]);
+        assert_eq!(interval.difference(&Interval::new(9, 10)), vec![interval]);
+    }
+
+    #[test]
+    fn test_union_and_split() {
+        let interval = Interval::new(2, 4);
+
+        // touching is enough, there's nothing between 4 & 5
+        assert_eq!(
+            interval.union(&Interval::new(5, 6)),
+            Some(Interval::new(2, 6))
+        );
+        assert_eq!(interval.union(&Interval::new(6, 7)), None);
+
+        assert_eq!(
+            interval.split_at(3),
+            (Some(Interval::new(2, 2)), Some(Interval::new(3, 4)))
+        );
+        assert_eq!(interval.split_at(2), (None, Some(interval)));
+        assert_eq!(interval.split_at(5), (Some(interval), None));
+        assert_eq!(interval.shift(-2), Some(Interval::new(0, 2)));
+    }
+
+    #[test]
+    fn test_set_merges() {
+        let merged = set(&[(10, 12), (1, 3), (4, 5), (11, 20), (30, 30)]);
+
+        assert_eq!(merged, set(&[(1, 5), (10, 20), (30, 30)]));
+        assert_eq!(merged.len(), 3);
+        assert_eq!(merged.total_length(), Some(5 + 11 + 1));
+        assert_eq!((merged.min(), merged.max()), (Some(1), Some(30)));
+
+        assert!(merged.contains(15) && merged.contains(30));
+        assert!(!merged.contains(6) && !merged.contains(31));
+
+        // bridging the gap between everything
+        let mut bridged = merged.clone();
+        bridged.insert(Interval::new(6, 29));
+        assert_eq!(bridged, set(&[(1, 30)]));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[(1, 5), (10, 20)]);
+        let b = set(&[(4, 12), (18, 25)]);
+
+        assert_eq!(a.union(&b), set(&[(1, 25)]));
+        assert_eq!(a.intersection(&b), set(&[(4, 5), (10, 12), (18, 20)]));
+        assert_eq!(a.difference(&b), set(&[(1, 3), (13, 17)]));
+        assert_eq!(b.difference(&a), set(&[(6, 9), (21, 25)]));
+
+        assert_eq!(a.split_at(12), (set(&[(1, 5), (10, 11)]), set(&[(12, 20)])));
+        assert!(IntervalSet::new().is_empty());
+        assert_eq!(IntervalSet::from(Interval::new(1, 2)), set(&[(1, 2)]));
+    }
+
+    #[test]
+    fn test_i64_limits() {
+        let everything = Interval::new(i64::MIN, i64::MAX);
+        let lowest = Interval::new(i64::MIN, -1);
+        let highest = Interval::new(0, i64::MAX);
+
+        // too many values to count
+        assert_eq!(everything.length(), None);
+        assert_eq!(lowest.length(), None);
+        assert_eq!(Interval::new(i64::MIN, -2).length(), Some(i64::MAX));
+        assert_eq!(Interval::new(i64::MAX, i64::MAX).length(), Some(1));
+
+        assert_eq!(
+            Interval::with_length(i64::MAX, 1),
+            Some(Interval::new(i64::MAX, i64::MAX))
+        );
+        assert_eq!(Interval::with_length(i64::MAX, 2), None);
+        assert_eq!(highest.shift(-1), Some(Interval::new(-1, i64::MAX - 1)));
+        assert_eq!(highest.shift(1), None);
+        assert_eq!(lowest.shift(-1), None);
+
+        assert_eq!(lowest.union(&highest), Some(everything));
+        assert_eq!(highest.union(&lowest), Some(everything));
+        assert_eq!(everything.difference(&highest), vec![lowest]);
+        assert_eq!(everything.difference(&lowest), vec![highest]);
+        assert_eq!(highest.difference(&everything), vec![]);
+
+        // touching at both ends of the i64s
+        let mut set = IntervalSet::from(Interval::new(i64::MAX, i64::MAX));
+        set.insert(Interval::new(i64::MIN, i64::MIN));
+        set.insert(Interval::new(2, i64::MAX - 1));
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.total_length(), Some(i64::MAX));
+        set.insert(Interval::new(i64::MIN + 1, 1));
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![&everything]);
+        assert_eq!(set.total_length(), None);
+        assert_eq!(
+            IntervalSet::from(everything).difference(&set),
+            IntervalSet::new()
+        );
+    }
+}
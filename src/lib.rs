@@ -0,0 +1,23 @@
+// shared plumbing & every day's solution, run through `cargo run --bin aoc`
+// i.e. `use advent_of_code_2023::input::{lines, load_input};`
+
+pub mod answer;
+pub mod answers;
+pub mod days;
+pub mod examples;
+pub mod geometry;
+pub mod grid;
+pub mod html;
+pub mod input;
+pub mod interval;
+pub mod output;
+pub mod parse;
+pub mod puzzle;
+pub mod readme;
+pub mod runner;
+pub mod scaffold;
+pub mod site;
+pub mod solution;
+pub mod sparse_grid;
+#[cfg(test)]
+mod temp_dir;
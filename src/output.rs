@@ -0,0 +1,85 @@
+use std::time::Duration;
+
+pub fn format_table(header: &[&str], rows: &[Vec<String>]) -> String {
+    // widest cell in each column (including the header)
+    let column_widths: Vec<usize> = (0..header.len())
+        .map(|column| {
+            rows.iter()
+                .map(|row| row[column].chars().count())
+                .chain([header[column].chars().count()])
+                .max()
+                .unwrap()
+        })
+        .collect();
+
+    let format_row = |cells: &[String]| -> String {
+        cells
+            .iter()
+            .zip(&column_widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<String>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+
+    let header: Vec<String> = header.iter().map(|cell| cell.to_string()).collect();
+    let separator: Vec<String> = column_widths
+        .iter()
+        .map(|width| "-".repeat(*width))
+        .collect();
+
+    let mut lines = vec![format_row(&header), format_row(&separator)];
+    lines.extend(rows.iter().map(|row| format_row(row)));
+
+    lines.join("\n")
+}
+
+// short human readable duration, e.g. "1.23s", "45.60ms", "7.89µs"
+pub fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs_f64();
+
+    if seconds >= 1.0 {
+        format!("{seconds:.2}s")
+    } else if seconds >= 0.001 {
+        format!("{:.2}ms", seconds * 1_000.0)
+    } else {
+        format!("{:.2}µs", seconds * 1_000_000.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_table() {
+        let table = format_table(
+            &["Year", "Day", "Answer"],
+            &[
+                vec!["2023".to_string(), "1".to_string(), "55621".to_string()],
+                vec!["2022".to_string(), "23".to_string(), "4".to_string()],
+            ],
+        );
+
+        assert_eq!(
+            table,
+            "
+Year  Day  Answer
+----  ---  ------
+2023  1    55621
+2022  23   4
+            "
+            .trim()
+        );
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_millis(7930)), "7.93s");
+        assert_eq!(format_duration(Duration::from_millis(490)), "490.00ms");
+        assert_eq!(format_duration(Duration::from_micros(1500)), "1.50ms");
+        assert_eq!(format_duration(Duration::from_nanos(73_000)), "73.00µs");
+        assert_eq!(format_duration(Duration::ZERO), "0.00µs");
+    }
+}
@@ -0,0 +1,444 @@
+// shared parsing helpers, so a malformed input says where it went wrong rather than unwrapping
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ParseError {
+    // 1-based, None until the error's been placed in the whole input
+    pub line: Option<usize>,
+    // 1-based, in chars
+    pub column: usize,
+    // the text that couldn't be parsed, empty if the line ended too early
+    pub text: String,
+    pub expected: String,
+    // the whole input ran out before it was found, i.e. a missing line
+    pub end_of_input: bool,
+}
+
+impl ParseError {
+    // for when there aren't enough lines, placed on the line after the last one
+    pub fn end_of_input(input: &str, expected: &str) -> ParseError {
+        ParseError {
+            line: Some(input.lines().count() + 1),
+            column: 1,
+            text: String::new(),
+            expected: expected.to_string(),
+            end_of_input: true,
+        }
+    }
+
+    pub fn with_line(mut self, line: usize) -> ParseError {
+        self.line = Some(line);
+        self
+    }
+
+    // for errors from a chunk of the input, i.e. one block of several lines
+    pub fn offset_lines(mut self, lines: usize) -> ParseError {
+        self.line = self.line.map(|line| line + lines);
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(line) = self.line {
+            write!(f, "line {line}, ")?;
+        }
+        write!(f, "column {}: expected {}, ", self.column, self.expected)?;
+
+        match (self.end_of_input, self.text.is_empty()) {
+            (true, _) => write!(f, "found the end of the input"),
+            (false, true) => write!(f, "found the end of the line"),
+            (false, false) => write!(f, "found {:?}", self.text),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// where `part` starts in `whole`, when it's a slice of it (from split, trim, etc.)
+pub(crate) fn offset_in(whole: &str, part: &str) -> Option<usize> {
+    let start = (part.as_ptr() as usize).checked_sub(whole.as_ptr() as usize)?;
+
+    (start <= whole.len()).then_some(start)
+}
+
+// 1-based line number of a slice of `input`
+pub fn line_of(input: &str, part: &str) -> usize {
+    let start = offset_in(input, part).unwrap_or(0);
+
+    input[..start].matches('\n').count() + 1
+}
+
+// parses a single line, every &str handed to it is expected to be a slice of that line
+// so errors can point at the column the offending text starts at
+pub struct LineParser<'a> {
+    line: &'a str,
+}
+
+impl<'a> LineParser<'a> {
+    pub fn new(line: &'a str) -> LineParser<'a> {
+        LineParser { line }
+    }
+
+    pub fn error(&self, at: &str, expected: &str) -> ParseError {
+        // text that isn't from the line is reported at its end
+        let start = offset_in(self.line, at).unwrap_or(self.line.len());
+
+        ParseError {
+            line: None,
+            column: self.line[..start].chars().count() + 1,
+            text: at.to_string(),
+            expected: expected.to_string(),
+            end_of_input: false,
+        }
+    }
+
+    pub fn strip_prefix(&self, text: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        text.strip_prefix(prefix)
+            .ok_or_else(|| self.error(text, &format!("{prefix:?}")))
+    }
+
+    pub fn split_once(
+        &self,
+        text: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        text.split_once(delimiter)
+            .ok_or_else(|| self.error(text, &format!("{delimiter:?}")))
+    }
+
+    pub fn number<T: FromStr>(&self, text: &'a str) -> Result<T, ParseError> {
+        let text = text.trim();
+
+        text.parse().map_err(|_| self.error(text, "a number"))
+    }
+
+    // "41 48  83" -> [41, 48, 83]
+    pub fn numbers<T: FromStr>(&self, text: &'a str) -> Result<Vec<T>, ParseError> {
+        text.split_whitespace()
+            .map(|number| self.number(number))
+            .collect()
+    }
+
+    // "Time:   7  15" -> ("Time", "7  15")
+    pub fn key_value(&self, text: &'a str) -> Result<(&'a str, &'a str), ParseError> {
+        let (key, value) = self.split_once(text, ":")?;
+
+        Ok((key.trim(), value.trim()))
+    }
+
+    // the value of a header that has to be `key`, i.e. "seeds: 79 14" -> "79 14"
+    pub fn value_for(&self, text: &'a str, key: &str) -> Result<&'a str, ParseError> {
+        let (found_key, value) = self.key_value(text)?;
+        if found_key != key {
+            return Err(self.error(found_key, &format!("{key:?}")));
+        }
+
+        Ok(value)
+    }
+}
+
+// a line of the input without its line ending, numbered from 1
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn is_blank(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+
+    pub fn parse<T: FromStr<Err = ParseError>>(&self) -> Result<T, ParseError> {
+        self.text
+            .parse()
+            .map_err(|err: ParseError| err.with_line(self.number))
+    }
+
+    // for lines that aren't worth a FromStr, errors from `parse` get this line's number
+    pub fn parse_with<T>(
+        &self,
+        parse: impl FnOnce(&LineParser<'a>, &'a str) -> Result<T, ParseError>,
+    ) -> Result<T, ParseError> {
+        parse(&LineParser::new(self.text), self.text).map_err(|err| err.with_line(self.number))
+    }
+}
+
+// every line, blank or not, CRLF endings are dropped so windows checkouts parse the same
+pub fn all_lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.split('\n').enumerate().map(|(idx, text)| Line {
+        number: idx + 1,
+        text: text.strip_suffix('\r').unwrap_or(text),
+    })
+}
+
+// the lines with something on them
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    all_lines(input).filter(|line| !line.is_blank())
+}
+
+// one T per non-blank line, errors get the line they're from
+pub fn parse_lines<T: FromStr<Err = ParseError>>(input: &str) -> Result<Vec<T>, ParseError> {
+    numbered_lines(input).map(|line| line.parse()).collect()
+}
+
+// a group of lines separated from the next by a blank line, i.e. one elf's snacks
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Record<'a> {
+    // the line the record starts on
+    pub number: usize,
+    // first line to last, still a slice of the input
+    pub text: &'a str,
+}
+
+impl<'a> Record<'a> {
+    // numbered from the top of the whole input
+    pub fn lines(&self) -> impl Iterator<Item = Line<'a>> + '_ {
+        numbered_lines(self.text).map(|line| Line {
+            number: line.number + self.number - 1,
+            ..line
+        })
+    }
+
+    // errors from T are relative to the record, they're moved to where it is in the input
+    pub fn parse<T: FromStr<Err = ParseError>>(&self) -> Result<T, ParseError> {
+        self.text.parse().map_err(|err: ParseError| match err.line {
+            Some(_) => err.offset_lines(self.number - 1),
+            None => err.with_line(self.number),
+        })
+    }
+}
+
+pub fn records(input: &str) -> Vec<Record<'_>> {
+    let mut records = vec![];
+    let mut current: Option<(Line, Line)> = None;
+
+    for line in all_lines(input) {
+        match (line.is_blank(), current) {
+            (true, Some((first, last))) => {
+                records.push(record_between(input, first, last));
+                current = None;
+            }
+            (true, None) => {}
+            (false, Some((first, _))) => current = Some((first, line)),
+            (false, None) => current = Some((line, line)),
+        }
+    }
+    if let Some((first, last)) = current {
+        records.push(record_between(input, first, last));
+    }
+
+    records
+}
+
+fn record_between<'a>(input: &'a str, first: Line<'a>, last: Line<'a>) -> Record<'a> {
+    let start = offset_in(input, first.text).unwrap_or(0);
+    let end = offset_in(input, last.text).unwrap_or(0) + last.text.len();
+
+    Record {
+        number: first.number,
+        text: &input[start..end],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Point(i32, i32);
+
+    impl FromStr for Point {
+        type Err = ParseError;
+
+        // "1,2"
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let parser = LineParser::new(s);
+            let (x, y) = parser.split_once(s, ",")?;
+
+            Ok(Point(parser.number(x)?, parser.number(y)?))
+        }
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(
+            parse_lines::<Point>("1,2\n\n3,-4\n"),
+            Ok(vec![Point(1, 2), Point(3, -4)])
+        );
+
+        assert_eq!(
+            parse_lines::<Point>("1,2\n\n3,x4"),
+            Err(ParseError {
+                line: Some(3),
+                column: 3,
+                text: "x4".to_string(),
+                expected: "a number".to_string(),
+                end_of_input: false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let error = parse_lines::<Point>("1,2\n3").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected \",\", found \"3\""
+        );
+
+        let parser = LineParser::new("Game 1");
+        assert_eq!(
+            parser.error("", "\":\"").to_string(),
+            "column 7: expected \":\", found the end of the line"
+        );
+
+        assert_eq!(
+            ParseError::end_of_input("Time: 7\r\n", "\"Distance\"").to_string(),
+            "line 2, column 1: expected \"Distance\", found the end of the input"
+        );
+        assert_eq!(ParseError::end_of_input("", "a line").line, Some(1));
+    }
+
+    #[test]
+    fn test_line_parser() {
+        let line = "Card  1: 41 48 | 83";
+        let parser = LineParser::new(line);
+
+        let rest = parser.strip_prefix(line, "Card").unwrap();
+        let (number, numbers) = parser.split_once(rest, ":").unwrap();
+        assert_eq!(parser.number::<u32>(number), Ok(1));
+        assert_eq!(
+            parser.numbers::<u32>(numbers),
+            Err(ParseError {
+                line: None,
+                column: 16,
+                text: "|".to_string(),
+                expected: "a number".to_string(),
+                end_of_input: false,
+            })
+        );
+
+        // columns count chars, not bytes
+        let line = "é x";
+        assert_eq!(
+            LineParser::new(line)
+                .number::<u8>(&line[3..])
+                .unwrap_err()
+                .column,
+            3
+        );
+    }
+
+    #[test]
+    fn test_key_value() {
+        let line = "Time:      7  15   30";
+        let parser = LineParser::new(line);
+
+        assert_eq!(parser.key_value(line), Ok(("Time", "7  15   30")));
+        assert_eq!(parser.value_for(line, "Time"), Ok("7  15   30"));
+        assert_eq!(
+            parser.value_for(line, "Distance").unwrap_err().to_string(),
+            "column 1: expected \"Distance\", found \"Time\""
+        );
+    }
+
+    #[test]
+    fn test_numbered_lines() {
+        let input = "1,2\r\n\r\n  \n3,4\r\n";
+
+        assert_eq!(
+            numbered_lines(input).collect::<Vec<Line>>(),
+            vec![
+                Line {
+                    number: 1,
+                    text: "1,2"
+                },
+                Line {
+                    number: 4,
+                    text: "3,4"
+                },
+            ]
+        );
+        assert_eq!(
+            parse_lines::<Point>(input),
+            Ok(vec![Point(1, 2), Point(3, 4)])
+        );
+
+        let line = numbered_lines("\nseeds: 79 x").next().unwrap();
+        let err = line
+            .parse_with(|parser, text| parser.numbers::<u32>(parser.value_for(text, "seeds")?))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 11: expected a number, found \"x\""
+        );
+    }
+
+    #[test]
+    fn test_records() {
+        let input = "\n1000\r\n2000\r\n\r\n4000\n\n\n5000\n6000\n";
+        let records = records(input);
+
+        assert_eq!(
+            records,
+            vec![
+                Record {
+                    number: 2,
+                    text: "1000\r\n2000"
+                },
+                Record {
+                    number: 5,
+                    text: "4000"
+                },
+                Record {
+                    number: 8,
+                    text: "5000\n6000"
+                },
+            ]
+        );
+        assert_eq!(
+            records[0]
+                .lines()
+                .map(|line| line.number)
+                .collect::<Vec<usize>>(),
+            vec![2, 3]
+        );
+        assert_eq!(super::records("\n\n").len(), 0);
+    }
+
+    #[test]
+    fn test_record_parse() {
+        struct Points(Vec<Point>);
+
+        impl FromStr for Points {
+            type Err = ParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                parse_lines(s).map(Points)
+            }
+        }
+
+        let input = "1,2\n\n3,4\n5,x";
+        assert_eq!(
+            records(input)[0].parse::<Points>().unwrap().0,
+            vec![Point(1, 2)]
+        );
+
+        let err = records(input)[1].parse::<Points>().err().unwrap();
+        assert_eq!(err.line, Some(4));
+
+        // single line errors are put on the record's first line
+        let err = records(input)[1].parse::<Point>().err().unwrap();
+        assert_eq!(err.line, Some(3));
+    }
+
+    #[test]
+    fn test_line_of() {
+        let input = "a\nb\n\nc";
+        assert_eq!(line_of(input, &input[0..1]), 1);
+        assert_eq!(line_of(input, &input[5..]), 4);
+    }
+}
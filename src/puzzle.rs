@@ -0,0 +1,238 @@
+// the puzzle's description as markdown, saved next to the solution so it can be read offline
+use crate::html::{inner_blocks, unescape};
+use crate::scaffold::{Layout, Scaffold};
+use crate::site::AOC_URL;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// dayNN.md beside dayNN.rs, or dayNN/puzzle.md for split days
+pub fn puzzle_path(module_path: &Path, layout: Layout) -> PathBuf {
+    match layout {
+        Layout::Single => module_path.with_extension("md"),
+        Layout::Split => module_path.join("puzzle.md"),
+    }
+}
+
+// for a day that's already been generated, in whichever layout it was
+pub fn existing_puzzle_path(root: &Path, year: u16, day: u8) -> Option<PathBuf> {
+    let module_path = Scaffold::new(year, day, Layout::Single).existing_module(root)?;
+    let layout = match module_path.is_dir() {
+        true => Layout::Split,
+        false => Layout::Single,
+    };
+
+    Some(puzzle_path(&module_path, layout))
+}
+
+// one section per <article>, part 2's only there once part 1 is solved
+pub fn puzzle_markdown(html: &str, year: u16, day: u8) -> String {
+    let mut markdown: String = inner_blocks(html, "article")
+        .into_iter()
+        .map(article_markdown)
+        .collect::<Vec<String>>()
+        .join("\n\n");
+
+    markdown += &format!("\n\n[{year} day {day}]({AOC_URL}/{year}/day/{day})\n");
+    markdown
+}
+
+pub fn save_puzzle(path: &Path, html: &str, year: u16, day: u8) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    fs::write(path, puzzle_markdown(html, year, day))
+}
+
+// the handful of tags puzzle descriptions use: h2, p, pre, code, em, a, ul & li
+fn article_markdown(html: &str) -> String {
+    let mut markdown = String::new();
+    let mut in_pre = false;
+    let mut in_code = false;
+    let mut links: Vec<String> = Vec::new();
+
+    let mut rest = html;
+    while !rest.is_empty() {
+        let Some(tag_start) = rest.find('<') else {
+            push_text(&mut markdown, rest, in_pre);
+            break;
+        };
+        push_text(&mut markdown, &rest[..tag_start], in_pre);
+
+        let Some(tag_end) = rest[tag_start..].find('>') else {
+            break;
+        };
+        let tag = &rest[tag_start + 1..tag_start + tag_end];
+        rest = &rest[tag_start + tag_end + 1..];
+
+        let closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split_whitespace()
+            .next()
+            .unwrap_or_default();
+
+        match (name, closing) {
+            ("h2", false) => markdown += "## ",
+            ("h2", true) | ("p", true) | ("ul", true) => markdown += "\n\n",
+            ("pre", false) => {
+                markdown += "```\n";
+                in_pre = true;
+            }
+            ("pre", true) => {
+                if !markdown.ends_with('\n') {
+                    markdown.push('\n');
+                }
+                markdown += "```\n\n";
+                in_pre = false;
+            }
+            // highlighting can't be shown in a code block or inline code
+            _ if in_pre => {}
+            ("code", _) => {
+                markdown.push('`');
+                in_code = !closing;
+            }
+            ("em", _) if !in_code => markdown += "**",
+            ("a", false) => {
+                links.push(attribute(tag, "href").unwrap_or_default());
+                markdown.push('[');
+            }
+            ("a", true) => {
+                let href = links.pop().unwrap_or_default();
+                let href = match href.starts_with('/') {
+                    true => format!("{AOC_URL}{href}"),
+                    false => href,
+                };
+                markdown += &format!("]({href})");
+            }
+            ("li", false) => markdown += "- ",
+            ("li", true) => markdown.push('\n'),
+            _ => {}
+        }
+    }
+
+    tidy(&markdown)
+}
+
+// text outside <pre> is wrapped however the page happened to be, so it's joined back up
+fn push_text(markdown: &mut String, html: &str, in_pre: bool) {
+    let text = unescape(html);
+    match in_pre {
+        true => *markdown += &text,
+        false => *markdown += &text.replace('\n', " "),
+    }
+}
+
+// "## --- Day 1: Trebuchet?! ---" -> "## Day 1: Trebuchet?!", no trailing spaces or runs of blank lines
+fn tidy(markdown: &str) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    let mut in_code_block = false;
+
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            lines.push(line.trim().to_string());
+            continue;
+        }
+        if in_code_block {
+            lines.push(line.to_string());
+            continue;
+        }
+
+        // leading spaces outside code blocks come from the page's formatting
+        let line = line.trim();
+        let line = match line.strip_prefix("## ") {
+            Some(heading) => format!("## {}", heading.trim_matches(|c| c == '-' || c == ' ')),
+            None => line.to_string(),
+        };
+
+        if line.is_empty() && lines.last().is_none_or(|last| last.is_empty()) {
+            continue;
+        }
+        lines.push(line);
+    }
+
+    lines.join("\n").trim_end().to_string()
+}
+
+// href="/2023/day/1/input" -> /2023/day/1/input
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = start + tag[start..].find('"')?;
+
+    Some(unescape(&tag[start..end]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUZZLE_HTML: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2><p>Something is wrong with global snow production, and you've been selected to take a look.
+The Elves have even given you a <a href="/2023/events">map</a>.</p>
+<p>For example:</p>
+<pre><code>1abc2
+<em>pqr3stu8vwx</em>
+a&lt;b
+</code></pre>
+<ul>
+<li>In <code>1abc2</code>, it's <code>12</code>.</li>
+<li>The <em>total</em> is <code><em>142</em></code>.</li>
+</ul>
+</article>
+<p>Your puzzle answer was <code>55621</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Some of the digits are <a href="https://example.com" target="_blank">spelled out</a>.</p></article>
+</main></body></html>"#;
+
+    #[test]
+    fn test_puzzle_markdown() {
+        assert_eq!(
+            puzzle_markdown(PUZZLE_HTML, 2023, 1),
+            "## Day 1: Trebuchet?!
+
+Something is wrong with global snow production, and you've been selected to take a look. The Elves have even given you a [map](https://adventofcode.com/2023/events).
+
+For example:
+
+```
+1abc2
+pqr3stu8vwx
+a<b
+```
+
+- In `1abc2`, it's `12`.
+- The **total** is `142`.
+
+## Part Two
+
+Some of the digits are [spelled out](https://example.com).
+
+[2023 day 1](https://adventofcode.com/2023/day/1)
+"
+        );
+    }
+
+    #[test]
+    fn test_puzzle_paths() {
+        assert_eq!(
+            puzzle_path(Path::new("src/days/y2023/day08.rs"), Layout::Single),
+            PathBuf::from("src/days/y2023/day08.md")
+        );
+        assert_eq!(
+            puzzle_path(Path::new("src/days/y2022/day04"), Layout::Split),
+            PathBuf::from("src/days/y2022/day04/puzzle.md")
+        );
+
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        assert_eq!(
+            existing_puzzle_path(root, 2023, 2),
+            Some(root.join("src/days/y2023/day02.md"))
+        );
+        assert_eq!(
+            existing_puzzle_path(root, 2023, 1),
+            Some(root.join("src/days/y2023/day01/puzzle.md"))
+        );
+        assert_eq!(existing_puzzle_path(root, 1999, 1), None);
+    }
+}
@@ -0,0 +1,232 @@
+// the readme's progress tables, rebuilt from the registry & answers.toml by `aoc --all --update-readme`
+use crate::answers::{AnswerStatus, Answers};
+use crate::input::CURRENT_YEAR;
+use crate::output::format_duration;
+use crate::runner::{total_time, SolutionResult};
+use crate::scaffold::{Layout, Scaffold};
+use crate::solution::DynSolution;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const README_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/readme.md");
+
+// everything between these is replaced
+pub const PROGRESS_START: &str =
+    "<!-- progress: generated by `cargo run --release --bin aoc -- --all --update-readme` -->";
+pub const PROGRESS_END: &str = "<!-- /progress -->";
+
+// the file a part is solved in, relative to the repo root
+// split days have a file per part, variants are dayNN_variant.rs beside the original
+pub fn solution_file(
+    root: &Path,
+    year: u16,
+    day: u8,
+    variant: Option<&str>,
+    part: u8,
+) -> Option<PathBuf> {
+    let scaffold = Scaffold::new(year, day, Layout::Single);
+
+    if let Some(variant) = variant {
+        let variant_path = scaffold
+            .year_dir()
+            .join(format!("day{day:02}_{variant}.rs"));
+        if root.join(&variant_path).exists() {
+            return Some(variant_path);
+        }
+    }
+
+    let module_path = scaffold.existing_module(root)?;
+    let module_path = module_path.strip_prefix(root).unwrap_or(&module_path);
+    match module_path.extension() {
+        Some(_) => Some(module_path.to_path_buf()),
+        None => Some(module_path.join(format!("part{part}.rs"))),
+    }
+}
+
+pub fn progress_markdown(
+    solutions: &[&dyn DynSolution],
+    answers: &Answers,
+    results: &[SolutionResult],
+    root: &Path,
+) -> String {
+    let row = |solution: &&dyn DynSolution, with_year: bool| {
+        let (year, day, variant) = (solution.year(), solution.day(), solution.variant());
+
+        // the answer store knows what's been solved, whichever variant solved it
+        let part = |part: u8| {
+            let solved = answers
+                .get(year, day, part)
+                .is_some_and(|known| known.status == AnswerStatus::Verified);
+            let mark = if solved { "✅" } else { "⬜" };
+
+            match solution_file(root, year, day, variant, part) {
+                Some(path) => format!("[{mark}](/{})", path.display()),
+                None => mark.to_string(),
+            }
+        };
+        let time = results
+            .iter()
+            .find(|result| (result.year, result.day, result.variant) == (year, day, variant))
+            .map_or("-".to_string(), |result| {
+                format_duration(result.total_time())
+            });
+
+        let mut day = format!("{day:02}");
+        if let Some(variant) = variant {
+            day += &format!(" 🐝 {variant}");
+        }
+
+        let mut cells = vec![day, part(1), part(2), time];
+        if with_year {
+            cells.insert(0, year.to_string());
+        }
+        format!("| {} |", cells.join(" | "))
+    };
+
+    let (this_year, other_years): (Vec<&dyn DynSolution>, Vec<&dyn DynSolution>) = solutions
+        .iter()
+        .partition(|solution| solution.year() == CURRENT_YEAR);
+
+    let mut lines = vec![
+        format!("# {CURRENT_YEAR} Challenges"),
+        "| Day | Part 1 | Part 2 | Time |".to_string(),
+        "| --- | --- | --- | --- |".to_string(),
+    ];
+    lines.extend(this_year.iter().map(|solution| row(solution, false)));
+
+    lines.push(String::new());
+    lines.push("#### Other years (doing some old ones for extra ⭐️⭐️⭐️️)".to_string());
+    lines.push("| Year | Day | Part 1 | Part 2 | Time |".to_string());
+    lines.push("| --- | --- | --- | --- | --- |".to_string());
+    lines.extend(other_years.iter().map(|solution| row(solution, true)));
+
+    lines.push(String::new());
+    lines.push("#### Performance".to_string());
+    lines.push(format!(
+        "Total time to run all challenges: {}",
+        format_duration(total_time(results))
+    ));
+
+    lines.join("\n")
+}
+
+// swaps out whatever's between the markers, None if they're missing
+pub fn replace_progress(readme: &str, progress: &str) -> Option<String> {
+    let start = readme.find(PROGRESS_START)? + PROGRESS_START.len();
+    let end = start + readme[start..].find(PROGRESS_END)?;
+
+    Some(format!(
+        "{}\n{progress}\n{}",
+        &readme[..start],
+        &readme[end..]
+    ))
+}
+
+pub fn update_readme(path: &Path, progress: &str) -> Result<(), String> {
+    let readme = fs::read_to_string(path)
+        .map_err(|err| format!("Failed to read {}: {err}", path.display()))?;
+    let readme = replace_progress(&readme, progress)
+        .ok_or_else(|| format!("{} is missing the progress markers", path.display()))?;
+
+    fs::write(path, readme).map_err(|err| format!("Failed to write {}: {err}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+    use crate::answers::Check;
+    use crate::days::{y2022, y2023};
+    use crate::runner::PartResult;
+    use std::time::Duration;
+
+    fn root() -> &'static Path {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+    }
+
+    #[test]
+    fn test_solution_file() {
+        assert_eq!(
+            solution_file(root(), 2023, 1, None, 2),
+            Some(PathBuf::from("src/days/y2023/day01/part2.rs"))
+        );
+        assert_eq!(
+            solution_file(root(), 2023, 2, None, 2),
+            Some(PathBuf::from("src/days/y2023/day02.rs"))
+        );
+        assert_eq!(
+            solution_file(root(), 2022, 23, Some("performance"), 1),
+            Some(PathBuf::from("src/days/y2022/day23_performance.rs"))
+        );
+        assert_eq!(solution_file(root(), 1999, 1, None, 1), None);
+    }
+
+    #[test]
+    fn test_progress_markdown() {
+        let answers = Answers::parse(
+            r#"
+[2022.23]
+part1 = { answer = "1", status = "verified" }
+part2 = { answer = "2", status = "verified" }
+
+[2023.02]
+part1 = { answer = "3", status = "verified" }
+part2 = { status = "unknown" }
+"#,
+        )
+        .unwrap();
+        let results = vec![SolutionResult {
+            year: 2023,
+            day: 2,
+            variant: None,
+            parse_time: Duration::from_millis(1),
+            parts: vec![PartResult {
+                part: 1,
+                answer: Answer::from(3u32),
+                check: Check::Pass,
+                time: Duration::from_millis(2),
+            }],
+        }];
+        let solutions: Vec<&dyn DynSolution> = vec![
+            &y2022::day23::Day23,
+            &y2022::day23_performance::Day23Performance,
+            &y2023::day02::Day02,
+        ];
+
+        assert_eq!(
+            progress_markdown(&solutions, &answers, &results, root()),
+            "# 2023 Challenges
+| Day | Part 1 | Part 2 | Time |
+| --- | --- | --- | --- |
+| 02 | [✅](/src/days/y2023/day02.rs) | [⬜](/src/days/y2023/day02.rs) | 3.00ms |
+
+#### Other years (doing some old ones for extra ⭐️⭐️⭐️️)
+| Year | Day | Part 1 | Part 2 | Time |
+| --- | --- | --- | --- | --- |
+| 2022 | 23 | [✅](/src/days/y2022/day23.rs) | [✅](/src/days/y2022/day23.rs) | - |
+| 2022 | 23 🐝 performance | [✅](/src/days/y2022/day23_performance.rs) | [✅](/src/days/y2022/day23_performance.rs) | - |
+
+#### Performance
+Total time to run all challenges: 3.00ms"
+        );
+    }
+
+    #[test]
+    fn test_replace_progress() {
+        let readme = format!("# Title\n{PROGRESS_START}\nold\nlines\n{PROGRESS_END}\n# Usage\n");
+
+        assert_eq!(
+            replace_progress(&readme, "new"),
+            Some(format!(
+                "# Title\n{PROGRESS_START}\nnew\n{PROGRESS_END}\n# Usage\n"
+            ))
+        );
+        assert_eq!(replace_progress("# Title\n", "new"), None);
+    }
+
+    #[test]
+    fn test_readme_has_markers() {
+        let readme = fs::read_to_string(README_PATH).unwrap();
+        assert!(replace_progress(&readme, "").is_some());
+    }
+}
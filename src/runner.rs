@@ -0,0 +1,625 @@
+use crate::answer::Answer;
+use crate::answers::{Answers, Check};
+use crate::days::registry;
+use crate::input::{InputError, InputSource, CURRENT_YEAR};
+use crate::output::{format_duration, format_table};
+use crate::parse::ParseError;
+use crate::solution::DynSolution;
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+pub const USAGE: &str = "Usage: aoc [--all] [--year <year>] [--day <day>] [--part <1|2>]
+           [--iterations <n>] [--sort <day|parse|part1|part2|total>]
+           [--inputs <dir>] [--input-set <name>] [--submit] [--session-file <file>]
+           [--update-readme]
+
+Examples:
+  aoc --all                          run every solution
+  aoc --year 2022                    run every 2022 solution
+  aoc --year 2023 --day 7 --part 2   run a single part
+  aoc --day 7                        days without a year are from 2023
+  aoc --all --iterations 20 --sort total
+                                     average timings over 20 runs, slowest first
+  aoc --all --input-set alice        use inputs/alice/*.txt instead of inputs/*.txt
+  aoc --day 7 --part 1 --submit      submit the answer & record the outcome in answers.toml
+  aoc --all --update-readme          rewrite the readme's progress tables, with timings
+
+Inputs are read from the repo's inputs dir, set AOC_INPUTS_DIR or pass --inputs to use another.
+AOC_INPUT_SET can be used in place of --input-set.
+Submitting needs a session token, from --session-file, AOC_SESSION_TOKEN or
+<config dir>/advent-of-code/session.";
+
+// which solutions & parts to run, from the command line
+#[derive(Debug, PartialEq)]
+pub struct Options {
+    pub year: Option<u16>,
+    pub day: Option<u8>,
+    pub part: Option<u8>,
+    // timings are averaged over this many runs
+    pub iterations: u32,
+    pub sort: SortBy,
+    // override AOC_INPUTS_DIR & AOC_INPUT_SET
+    pub inputs: Option<PathBuf>,
+    pub input_set: Option<String>,
+    // send the answer to adventofcode.com, a single day & part only
+    pub submit: bool,
+    // where the session token is read from, over AOC_SESSION_TOKEN & the config dir
+    pub session_file: Option<PathBuf>,
+    // rebuild the readme's progress tables from the registry, answers.toml & these timings
+    pub update_readme: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            year: None,
+            day: None,
+            part: None,
+            iterations: 1,
+            sort: SortBy::Day,
+            inputs: None,
+            input_set: None,
+            submit: false,
+            session_file: None,
+            update_readme: false,
+        }
+    }
+}
+
+impl Options {
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Options, String> {
+        let mut options = Options::default();
+        let mut all = false;
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--all" => all = true,
+                "--year" => options.year = Some(parse_value(&arg, args.next())?),
+                "--day" => options.day = Some(parse_value(&arg, args.next())?),
+                "--part" => options.part = Some(parse_value(&arg, args.next())?),
+                "--iterations" => options.iterations = parse_value(&arg, args.next())?,
+                "--sort" => options.sort = parse_value(&arg, args.next())?,
+                "--inputs" => options.inputs = Some(parse_value(&arg, args.next())?),
+                "--input-set" => options.input_set = Some(parse_value(&arg, args.next())?),
+                "--submit" => options.submit = true,
+                "--update-readme" => options.update_readme = true,
+                "--session-file" => options.session_file = Some(parse_value(&arg, args.next())?),
+                _ => return Err(format!("Unknown argument: {arg}")),
+            }
+        }
+
+        let has_selection =
+            options.year.is_some() || options.day.is_some() || options.part.is_some();
+        if all && has_selection {
+            return Err("--all can't be combined with --year, --day or --part".to_string());
+        }
+        if !all && !has_selection {
+            return Err("Nothing to run, pass --all or pick a --year/--day".to_string());
+        }
+        if options.part.is_some_and(|part| part != 1 && part != 2) {
+            return Err("--part must be 1 or 2".to_string());
+        }
+        if options.iterations == 0 {
+            return Err("--iterations must be at least 1".to_string());
+        }
+        if options.submit && (options.day.is_none() || options.part.is_none()) {
+            return Err("--submit needs a single --day & --part".to_string());
+        }
+        if options.update_readme && has_selection {
+            return Err("--update-readme needs --all, to time every solution".to_string());
+        }
+
+        // a day on its own is from this year
+        if options.day.is_some() && options.year.is_none() {
+            options.year = Some(CURRENT_YEAR);
+        }
+
+        Ok(options)
+    }
+
+    pub fn selects(&self, solution: &dyn DynSolution) -> bool {
+        self.year.is_none_or(|year| year == solution.year())
+            && self.day.is_none_or(|day| day == solution.day())
+    }
+
+    pub fn input_source(&self) -> InputSource {
+        InputSource::resolve(self.inputs.clone(), self.input_set.clone())
+    }
+
+    pub fn parts(&self) -> Vec<u8> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        }
+    }
+}
+
+fn parse_value<T: FromStr>(flag: &str, value: Option<String>) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("{flag} needs a value"))?;
+
+    value
+        .parse()
+        .map_err(|_| format!("Invalid value for {flag}: {value}"))
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum SortBy {
+    Day,
+    Parse,
+    Part1,
+    Part2,
+    Total,
+}
+
+impl FromStr for SortBy {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "day" => Ok(SortBy::Day),
+            "parse" => Ok(SortBy::Parse),
+            "part1" => Ok(SortBy::Part1),
+            "part2" => Ok(SortBy::Part2),
+            "total" => Ok(SortBy::Total),
+            _ => Err(()),
+        }
+    }
+}
+
+pub struct PartResult {
+    pub part: u8,
+    pub answer: Answer,
+    // against answers.toml
+    pub check: Check,
+    // mean time across all iterations
+    pub time: Duration,
+}
+
+pub struct SolutionResult {
+    pub year: u16,
+    pub day: u8,
+    pub variant: Option<&'static str>,
+    // mean time across all iterations
+    pub parse_time: Duration,
+    pub parts: Vec<PartResult>,
+}
+
+impl SolutionResult {
+    pub fn part(&self, part: u8) -> Option<&PartResult> {
+        self.parts.iter().find(|result| result.part == part)
+    }
+
+    pub fn failed(&self) -> bool {
+        self.parts.iter().any(|result| result.check.failed())
+    }
+
+    fn part_time(&self, part: u8) -> Duration {
+        self.part(part).map_or(Duration::ZERO, |result| result.time)
+    }
+
+    pub fn total_time(&self) -> Duration {
+        self.parse_time
+            + self
+                .parts
+                .iter()
+                .map(|result| result.time)
+                .sum::<Duration>()
+    }
+}
+
+// why a run stopped, the first input that couldn't be read or parsed
+#[derive(Debug)]
+pub enum RunError {
+    Input(InputError),
+    Parse { path: PathBuf, error: ParseError },
+}
+
+impl Display for RunError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RunError::Input(err) => write!(f, "{err}"),
+            RunError::Parse { path, error } => {
+                write!(f, "Invalid input file {}: {error}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for RunError {}
+
+impl From<InputError> for RunError {
+    fn from(err: InputError) -> RunError {
+        RunError::Input(err)
+    }
+}
+
+pub fn selected_solutions(options: &Options) -> Vec<&'static dyn DynSolution> {
+    registry()
+        .into_iter()
+        .filter(|solution| options.selects(*solution))
+        .collect()
+}
+
+pub fn run_solution(
+    solution: &dyn DynSolution,
+    input: &str,
+    parts: &[u8],
+    iterations: u32,
+    answers: &Answers,
+) -> Result<SolutionResult, ParseError> {
+    let mut parse_time = Duration::ZERO;
+    let mut part_times = vec![Duration::ZERO; parts.len()];
+    let mut answers_found = Vec::new();
+
+    for _ in 0..iterations {
+        // parse once per iteration, share the parsed input between both parts
+        let start = Instant::now();
+        let parsed = solution.parse(input)?;
+        parse_time += start.elapsed();
+
+        // answers are the same every iteration, keep the last set
+        answers_found = parts
+            .iter()
+            .zip(part_times.iter_mut())
+            .map(|(part, part_time)| {
+                let start = Instant::now();
+                let answer = match part {
+                    1 => solution.part1(parsed.as_ref()),
+                    _ => solution.part2(parsed.as_ref()),
+                };
+                *part_time += start.elapsed();
+
+                answer
+            })
+            .collect();
+    }
+
+    Ok(SolutionResult {
+        year: solution.year(),
+        day: solution.day(),
+        variant: solution.variant(),
+        parse_time: parse_time / iterations,
+        parts: parts
+            .iter()
+            .zip(answers_found)
+            .zip(part_times)
+            .map(|((part, answer), time)| PartResult {
+                part: *part,
+                check: answers.check(solution.year(), solution.day(), *part, &answer),
+                answer,
+                time: time / iterations,
+            })
+            .collect(),
+    })
+}
+
+pub fn run(options: &Options, answers: &Answers) -> Result<Vec<SolutionResult>, RunError> {
+    let inputs = options.input_source();
+
+    let mut results = selected_solutions(options)
+        .into_iter()
+        .map(|solution| {
+            let input = inputs.load(solution.year(), solution.day())?;
+
+            run_solution(
+                solution,
+                &input,
+                &options.parts(),
+                options.iterations,
+                answers,
+            )
+            .map_err(|error| RunError::Parse {
+                path: inputs.path(solution.year(), solution.day()),
+                error,
+            })
+        })
+        .collect::<Result<Vec<SolutionResult>, RunError>>()?;
+
+    sort_results(&mut results, options.sort);
+
+    Ok(results)
+}
+
+pub fn sort_results(results: &mut [SolutionResult], sort_by: SortBy) {
+    // registry order is by day, timings are sorted slowest first
+    match sort_by {
+        SortBy::Day => results.sort_by_key(|result| (result.year, result.day)),
+        SortBy::Parse => results.sort_by_key(|result| std::cmp::Reverse(result.parse_time)),
+        SortBy::Part1 => results.sort_by_key(|result| std::cmp::Reverse(result.part_time(1))),
+        SortBy::Part2 => results.sort_by_key(|result| std::cmp::Reverse(result.part_time(2))),
+        SortBy::Total => results.sort_by_key(|result| std::cmp::Reverse(result.total_time())),
+    }
+}
+
+pub fn results_table(results: &[SolutionResult]) -> String {
+    let answer = |result: &SolutionResult, part: u8| match result.part(part) {
+        Some(PartResult { answer, check, .. }) => format!("{answer} [{check}]"),
+        None => "-".to_string(),
+    };
+    let part_time = |result: &SolutionResult, part: u8| match result.part(part) {
+        Some(part_result) => format_duration(part_result.time),
+        None => "-".to_string(),
+    };
+
+    let rows: Vec<Vec<String>> = results
+        .iter()
+        .map(|result| {
+            vec![
+                result.year.to_string(),
+                format!("{:02}", result.day),
+                result.variant.unwrap_or("").to_string(),
+                answer(result, 1),
+                answer(result, 2),
+                format_duration(result.parse_time),
+                part_time(result, 1),
+                part_time(result, 2),
+                format_duration(result.total_time()),
+            ]
+        })
+        .collect();
+
+    format_table(
+        &[
+            "Year", "Day", "Variant", "Part 1", "Part 2", "Parse", "Part 1", "Part 2", "Total",
+        ],
+        &rows,
+    )
+}
+
+pub fn total_time(results: &[SolutionResult]) -> Duration {
+    results.iter().map(|result| result.total_time()).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::temp_dir::TempDir;
+    use std::path::Path;
+
+    fn args(args: &str) -> Vec<String> {
+        args.split_whitespace().map(String::from).collect()
+    }
+
+    fn example_result(day: u8, parse_ms: u64, part1_ms: u64, part2_ms: u64) -> SolutionResult {
+        SolutionResult {
+            year: 2023,
+            day,
+            variant: None,
+            parse_time: Duration::from_millis(parse_ms),
+            parts: vec![
+                PartResult {
+                    part: 1,
+                    answer: format!("{day}1").into(),
+                    check: Check::Pass,
+                    time: Duration::from_millis(part1_ms),
+                },
+                PartResult {
+                    part: 2,
+                    answer: format!("{day}2").into(),
+                    check: Check::Unknown,
+                    time: Duration::from_millis(part2_ms),
+                },
+            ],
+        }
+    }
+
+    fn days(results: &[SolutionResult]) -> Vec<u8> {
+        results.iter().map(|result| result.day).collect()
+    }
+
+    #[test]
+    fn test_options_from_args() {
+        assert_eq!(Options::from_args(args("--all")), Ok(Options::default()));
+
+        assert_eq!(
+            Options::from_args(args("--year 2023 --day 7 --part 2")),
+            Ok(Options {
+                year: Some(2023),
+                day: Some(7),
+                part: Some(2),
+                ..Options::default()
+            })
+        );
+
+        assert_eq!(
+            Options::from_args(args("--year 2022")),
+            Ok(Options {
+                year: Some(2022),
+                ..Options::default()
+            })
+        );
+
+        // day without a year defaults to this year
+        assert_eq!(
+            Options::from_args(args("--day 3")),
+            Ok(Options {
+                year: Some(2023),
+                day: Some(3),
+                ..Options::default()
+            })
+        );
+
+        assert_eq!(
+            Options::from_args(args("--all --inputs /tmp/aoc --input-set alice")),
+            Ok(Options {
+                inputs: Some(PathBuf::from("/tmp/aoc")),
+                input_set: Some("alice".to_string()),
+                ..Options::default()
+            })
+        );
+
+        assert_eq!(
+            Options::from_args(args("--day 7 --part 1 --submit --session-file ~/aoc")),
+            Ok(Options {
+                year: Some(2023),
+                day: Some(7),
+                part: Some(1),
+                submit: true,
+                session_file: Some(PathBuf::from("~/aoc")),
+                ..Options::default()
+            })
+        );
+
+        assert_eq!(
+            Options::from_args(args("--all --iterations 20 --sort part2")),
+            Ok(Options {
+                iterations: 20,
+                sort: SortBy::Part2,
+                ..Options::default()
+            })
+        );
+    }
+
+    #[test]
+    fn test_invalid_args() {
+        assert!(Options::from_args(args("")).is_err());
+        assert!(Options::from_args(args("--iterations 5")).is_err());
+        assert!(Options::from_args(args("--all --year 2022")).is_err());
+        assert!(Options::from_args(args("--day")).is_err());
+        assert!(Options::from_args(args("--day seven")).is_err());
+        assert!(Options::from_args(args("--day 7 --part 3")).is_err());
+        assert!(Options::from_args(args("--all --iterations 0")).is_err());
+        assert!(Options::from_args(args("--all --sort fastest")).is_err());
+        assert!(Options::from_args(args("--bin 07b")).is_err());
+        assert!(Options::from_args(args("--all --submit")).is_err());
+        assert!(Options::from_args(args("--day 7 --submit")).is_err());
+        assert!(Options::from_args(args("--year 2023 --update-readme")).is_err());
+    }
+
+    #[test]
+    fn test_selected_solutions() {
+        let options = Options::from_args(args("--year 2022 --day 23")).unwrap();
+        let selected = selected_solutions(&options);
+
+        // original & performance variant
+        assert_eq!(selected.len(), 2);
+        assert_eq!(selected[0].variant(), None);
+        assert_eq!(selected[1].variant(), Some("performance"));
+
+        let options = Options::from_args(args("--year 2023")).unwrap();
+        assert!(selected_solutions(&options)
+            .iter()
+            .all(|solution| solution.year() == 2023));
+
+        let options = Options::from_args(args("--year 1999")).unwrap();
+        assert!(selected_solutions(&options).is_empty());
+    }
+
+    #[test]
+    fn test_run_single_part() {
+        let options = Options::from_args(args("--year 2023 --day 2 --part 2")).unwrap();
+        let results = run(&options, &Answers::load().unwrap()).unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].parts.len(), 1);
+        assert!(results[0].part(1).is_none());
+        assert_eq!(results[0].part(2).unwrap().answer, "72227");
+        assert_eq!(results[0].part(2).unwrap().check, Check::Pass);
+    }
+
+    #[test]
+    fn test_run_iterations() {
+        let options = Options::from_args(args("--day 5 --iterations 3")).unwrap();
+        let results = run(&options, &Answers::default()).unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].part(1).unwrap().answer, "382895070");
+        assert_eq!(results[0].part(1).unwrap().check, Check::Unknown);
+        assert_eq!(results[0].part(2).unwrap().answer, "17729182");
+        assert!(results[0].total_time() >= results[0].parse_time);
+    }
+
+    #[test]
+    fn test_run_missing_input() {
+        let options =
+            Options::from_args(args("--day 1 --inputs /tmp/aoc --input-set nobody")).unwrap();
+        let err = run(&options, &Answers::default()).err().unwrap();
+
+        assert!(
+            matches!(err, RunError::Input(err) if err.path == Path::new("/tmp/aoc/nobody/01.txt"))
+        );
+    }
+
+    #[test]
+    fn test_run_invalid_input() {
+        let dir = TempDir::new("invalid_input");
+        std::fs::write(dir.join("02.txt"), "Game 1: 3 blue\nGame two: 1 red\n").unwrap();
+
+        let options = Options {
+            inputs: Some(dir.path().to_path_buf()),
+            ..Options::from_args(args("--day 2")).unwrap()
+        };
+        let err = run(&options, &Answers::default()).err().unwrap();
+
+        assert!(matches!(&err, RunError::Parse { error, .. } if error.line == Some(2)));
+        assert!(err.to_string().starts_with(&format!(
+            "Invalid input file {}: line 2,",
+            dir.join("02.txt").display()
+        )));
+    }
+
+    #[test]
+    fn test_sort_results() {
+        let mut results = vec![
+            example_result(1, 5, 1, 1),
+            example_result(2, 1, 9, 1),
+            example_result(3, 1, 1, 4),
+        ];
+
+        sort_results(&mut results, SortBy::Parse);
+        assert_eq!(days(&results), vec![1, 2, 3]);
+
+        sort_results(&mut results, SortBy::Part1);
+        assert_eq!(days(&results), vec![2, 1, 3]);
+
+        // ties keep their previous order
+        sort_results(&mut results, SortBy::Part2);
+        assert_eq!(days(&results), vec![3, 2, 1]);
+
+        sort_results(&mut results, SortBy::Total);
+        assert_eq!(days(&results), vec![2, 1, 3]);
+
+        sort_results(&mut results, SortBy::Day);
+        assert_eq!(days(&results), vec![1, 2, 3]);
+
+        assert_eq!(total_time(&results), Duration::from_millis(24));
+    }
+
+    #[test]
+    fn test_failed() {
+        let mut result = example_result(1, 1, 2, 3);
+        assert!(!result.failed());
+
+        result.parts[1].check = Check::Fail {
+            expected: "281".to_string(),
+        };
+        assert!(result.failed());
+
+        // an answer adventofcode.com already rejected
+        result.parts[1].check = Check::KnownWrong;
+        assert!(result.failed());
+    }
+
+    #[test]
+    fn test_results_table() {
+        let mut results = vec![example_result(1, 1, 2, 3), example_result(2, 1, 1, 1)];
+        results[0].parts[1].check = Check::KnownWrong;
+        results[1].parts[0].check = Check::Fail {
+            expected: "8".to_string(),
+        };
+
+        assert_eq!(
+            results_table(&results),
+            "
+Year  Day  Variant  Part 1                 Part 2            Parse   Part 1  Part 2  Total
+----  ---  -------  ---------------------  ----------------  ------  ------  ------  ------
+2023  01            11 [pass]              12 [known wrong]  1.00ms  2.00ms  3.00ms  6.00ms
+2023  02            21 [FAIL, expected 8]  22 [unknown]      1.00ms  1.00ms  1.00ms  3.00ms
+            "
+            .trim()
+        );
+    }
+}
@@ -0,0 +1,778 @@
+// generates the files for a new day & registers it, used by 00_generate
+use crate::examples::Example;
+use crate::input::CURRENT_YEAR;
+use crate::puzzle::puzzle_path;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+const SINGLE_TEMPLATE: &str = include_str!("days/template.rs");
+const SPLIT_TEMPLATE_MOD: &str = include_str!("days/template_split/mod.rs");
+const SPLIT_TEMPLATE_PART1: &str = include_str!("days/template_split/part1.rs");
+const SPLIT_TEMPLATE_PART2: &str = include_str!("days/template_split/part2.rs");
+
+pub const REGISTRY_MARKER: &str = "        // new days are added above this line by 00_generate";
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Layout {
+    // dayNN.rs, both parts share parsing
+    Single,
+    // dayNN/mod.rs, part1.rs & part2.rs
+    Split,
+}
+
+impl FromStr for Layout {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "single" => Ok(Layout::Single),
+            "split" => Ok(Layout::Split),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    AlreadyExists(PathBuf),
+    MissingRegistryMarker(PathBuf),
+    Io { path: PathBuf, source: io::Error },
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScaffoldError::AlreadyExists(path) => write!(f, "{} already exists", path.display()),
+            ScaffoldError::MissingRegistryMarker(path) => {
+                write!(f, "{} is missing the registry marker", path.display())
+            }
+            ScaffoldError::Io { path, source } => write!(f, "{}: {source}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum FileChange {
+    Create,
+    Modify,
+}
+
+impl Display for FileChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FileChange::Create => write!(f, "create"),
+            FileChange::Modify => write!(f, "modify"),
+        }
+    }
+}
+
+pub const USAGE: &str = "Usage: 00_generate [--year <year>] --day <day> [--layout <single|split>]
+                   [--puzzle-html <file>] [--no-open | --editor <cmd>] [--dry-run]
+                   [--session-file <file>] [--refresh-puzzle]
+
+With no arguments the year, day & layout are asked for.
+The puzzle's description is saved as markdown beside the solution, from --puzzle-html or the site.
+Downloading the input needs a session token, from --session-file, AOC_SESSION_TOKEN or
+<config dir>/advent-of-code/session.
+
+Examples:
+  00_generate --day 8                       2023 day 8, one file for both parts
+  00_generate --year 2022 --day 4 --layout split
+                                            2022 day 4, a file per part
+  00_generate --day 8 --editor \"code -r\"    open the new file in vscode
+  00_generate --day 8 --dry-run             list the files that would be written
+  00_generate --day 8 --puzzle-html ~/Downloads/8.html
+                                            start with tests for the page's examples
+  00_generate --day 8 --refresh-puzzle      re-save an existing day's description, i.e. with part 2
+
+Exit codes: 0 generated, 1 generating or downloading the input failed, 2 invalid arguments";
+
+#[derive(Debug, PartialEq)]
+pub struct GenerateOptions {
+    pub year: u16,
+    pub day: u8,
+    pub layout: Layout,
+    // a saved copy of the puzzle's page, to pull examples out of
+    pub puzzle_html: Option<PathBuf>,
+    // the new file is only opened when an editor is given
+    pub editor: Option<String>,
+    // print what would be written, without writing or downloading anything
+    pub dry_run: bool,
+    // where the session token is read from, over AOC_SESSION_TOKEN & the config dir
+    pub session_file: Option<PathBuf>,
+    // only re-save an existing day's description, once part 2's been unlocked
+    pub refresh_puzzle: bool,
+}
+
+impl GenerateOptions {
+    // None when there aren't any arguments, so the generator asks instead
+    pub fn from_args(
+        args: impl IntoIterator<Item = String>,
+    ) -> Result<Option<GenerateOptions>, String> {
+        let mut args = args.into_iter().peekable();
+        if args.peek().is_none() {
+            return Ok(None);
+        }
+
+        let mut year = CURRENT_YEAR;
+        let mut day: Option<u8> = None;
+        let mut layout = Layout::Single;
+        let mut puzzle_html: Option<PathBuf> = None;
+        let mut editor: Option<String> = None;
+        let mut open = true;
+        let mut dry_run = false;
+        let mut session_file: Option<PathBuf> = None;
+        let mut refresh_puzzle = false;
+
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or_else(|| format!("{arg} needs a value"));
+            let invalid = |value: &str| format!("Invalid value for {arg}: {value}");
+
+            match arg.as_str() {
+                "--year" => {
+                    let value = value()?;
+                    year = value.parse().map_err(|_| invalid(&value))?;
+                }
+                "--day" => {
+                    let value = value()?;
+                    day = Some(value.parse().map_err(|_| invalid(&value))?);
+                }
+                "--layout" => {
+                    let value = value()?;
+                    layout = value.parse().map_err(|_| invalid(&value))?;
+                }
+                "--puzzle-html" => puzzle_html = Some(PathBuf::from(value()?)),
+                "--editor" => editor = Some(value()?),
+                "--no-open" => open = false,
+                "--dry-run" => dry_run = true,
+                "--session-file" => session_file = Some(PathBuf::from(value()?)),
+                "--refresh-puzzle" => refresh_puzzle = true,
+                _ => return Err(format!("Unknown argument: {arg}")),
+            }
+        }
+
+        let day = day.ok_or("--day is required")?;
+        if !(1..=25).contains(&day) {
+            return Err("--day must be between 1 and 25".to_string());
+        }
+
+        Ok(Some(GenerateOptions {
+            year,
+            day,
+            layout,
+            puzzle_html,
+            editor: editor.filter(|_| open),
+            dry_run,
+            session_file,
+            refresh_puzzle,
+        }))
+    }
+
+    pub fn scaffold(&self) -> Scaffold {
+        Scaffold::new(self.year, self.day, self.layout)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Scaffold {
+    pub year: u16,
+    pub day: u8,
+    pub layout: Layout,
+    // examples with an answer become tests, in place of the template's commented out ones
+    pub examples: Vec<Example>,
+}
+
+impl Scaffold {
+    pub fn new(year: u16, day: u8, layout: Layout) -> Scaffold {
+        Scaffold {
+            year,
+            day,
+            layout,
+            examples: Vec::new(),
+        }
+    }
+
+    pub fn with_examples(mut self, examples: Vec<Example>) -> Scaffold {
+        self.examples = examples;
+        self
+    }
+
+    pub fn year_dir(&self) -> PathBuf {
+        // src/days/y2022
+        Path::new("src/days").join(format!("y{}", self.year))
+    }
+
+    // dayNN.rs or dayNN/, relative to the repo root
+    pub fn module_path(&self) -> PathBuf {
+        match self.layout {
+            Layout::Single => self.year_dir().join(format!("day{:02}.rs", self.day)),
+            Layout::Split => self.year_dir().join(format!("day{:02}", self.day)),
+        }
+    }
+
+    // the puzzle's description, beside the solution
+    pub fn puzzle_path(&self) -> PathBuf {
+        puzzle_path(&self.module_path(), self.layout)
+    }
+
+    // i.e. "y2022::day04::Day04"
+    pub fn solution_path(&self) -> String {
+        format!("y{}::day{:02}::Day{:02}", self.year, self.day, self.day)
+    }
+
+    // new files & their contents, relative to the repo root
+    pub fn files(&self) -> Vec<(PathBuf, String)> {
+        let module_path = self.module_path();
+
+        match self.layout {
+            Layout::Single => vec![(
+                module_path,
+                self.with_example_tests(self.fill_template(SINGLE_TEMPLATE)),
+            )],
+            Layout::Split => vec![
+                (
+                    module_path.join("mod.rs"),
+                    self.with_example_tests(self.fill_template(SPLIT_TEMPLATE_MOD)),
+                ),
+                (
+                    module_path.join("part1.rs"),
+                    self.fill_template(SPLIT_TEMPLATE_PART1),
+                ),
+                (
+                    module_path.join("part2.rs"),
+                    self.fill_template(SPLIT_TEMPLATE_PART2),
+                ),
+            ],
+        }
+    }
+
+    fn fill_template(&self, template: &str) -> String {
+        template
+            .replace("00", &format!("{:02}", self.day))
+            .replace("DAY: u8 = 0;", &format!("DAY: u8 = {};", self.day))
+            .replace(
+                &format!("YEAR: u16 = {CURRENT_YEAR};"),
+                &format!("YEAR: u16 = {};", self.year),
+            )
+    }
+
+    fn with_example_tests(&self, code: String) -> String {
+        let examples: Vec<&Example> = self
+            .examples
+            .iter()
+            .filter(|example| example.answer.is_some())
+            .collect();
+
+        match code.find("// #[cfg(test)]") {
+            Some(tests_start) if !examples.is_empty() => {
+                code[..tests_start].to_string() + &self.example_tests(&examples)
+            }
+            _ => code,
+        }
+    }
+
+    fn example_tests(&self, examples: &[&Example]) -> String {
+        let name = format!("Day{:02}", self.day);
+
+        let mut tests = format!(
+            "#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    // #[test]
+    // fn test_main() {{
+    //     // checks against answers.toml
+    //     crate::answers::assert_answers::<{name}>();
+    // }}
+"
+        );
+
+        for (idx, example) in examples.iter().enumerate() {
+            let part = example.part;
+            // a second example for the same part gets its own test, test_part1_example_2
+            let nth = examples[..idx]
+                .iter()
+                .filter(|earlier| earlier.part == part)
+                .count();
+            let suffix = match nth {
+                0 => String::new(),
+                _ => format!("_{}", nth + 1),
+            };
+            let answer = example.answer.as_deref().unwrap_or_default();
+            let solved = format!("{name}::part{part}(&{name}::parse(example_input).unwrap())");
+
+            // numbers compare directly against the part's answer type, anything else as a string
+            let assertion = match answer.parse::<i128>() {
+                Ok(_) => format!("assert_eq!({solved}, {answer});"),
+                Err(_) => format!("assert_eq!({solved}.to_string(), {answer:?});"),
+            };
+            // leading whitespace can matter, i.e. indented columns of crates
+            let trim = match example.input.starts_with(char::is_whitespace) {
+                true => "trim_matches('\\n')",
+                false => "trim()",
+            };
+            let input = example.input.replace('\\', "\\\\").replace('"', "\\\"");
+
+            tests += &format!(
+                "
+    #[test]
+    fn test_part{part}_example{suffix}() {{
+        let example_input = \"
+{input}\"
+            .{trim};
+
+        {assertion}
+    }}
+"
+            );
+        }
+
+        tests + "}\n"
+    }
+
+    // either layout counts, a day can't be both
+    pub fn existing_module(&self, root: &Path) -> Option<PathBuf> {
+        [Layout::Single, Layout::Split]
+            .into_iter()
+            .map(|layout| root.join(Scaffold::new(self.year, self.day, layout).module_path()))
+            .find(|path| path.exists())
+    }
+
+    // every file create() would write, without writing anything
+    pub fn plan(&self, root: &Path) -> Result<Vec<(PathBuf, FileChange)>, ScaffoldError> {
+        if let Some(existing) = self.existing_module(root) {
+            return Err(ScaffoldError::AlreadyExists(existing));
+        }
+
+        let year_mod_path = root.join(self.year_dir()).join("mod.rs");
+        let year_mod_change = match year_mod_path.exists() {
+            true => FileChange::Modify,
+            false => FileChange::Create,
+        };
+
+        let mut plan: Vec<(PathBuf, FileChange)> = self
+            .files()
+            .into_iter()
+            .map(|(path, _)| (root.join(path), FileChange::Create))
+            .collect();
+        plan.push((year_mod_path, year_mod_change));
+        plan.push((root.join("src/days/mod.rs"), FileChange::Modify));
+        plan.push((root.join("answers.toml"), FileChange::Modify));
+
+        Ok(plan)
+    }
+
+    // writes the day's files & registers it, returns every file created or changed
+    pub fn create(&self, root: &Path) -> Result<Vec<(PathBuf, FileChange)>, ScaffoldError> {
+        let plan = self.plan(root)?;
+
+        for (path, contents) in self.files() {
+            let path = root.join(path);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).map_err(|source| ScaffoldError::Io {
+                    path: parent.to_path_buf(),
+                    source,
+                })?;
+            }
+            write_file(&path, &contents)?;
+        }
+
+        self.register(root)?;
+
+        Ok(plan)
+    }
+
+    fn register(&self, root: &Path) -> Result<(), ScaffoldError> {
+        let days_mod_path = root.join("src/days/mod.rs");
+        let year_mod_path = root.join(self.year_dir()).join("mod.rs");
+        let answers_path = root.join("answers.toml");
+
+        // first day of a year, the year needs its own module
+        let mut days_mod = read_file(&days_mod_path)?;
+        let year_mod = if year_mod_path.exists() {
+            read_file(&year_mod_path)?
+        } else {
+            days_mod = insert_year_module(&days_mod, self.year);
+            String::new()
+        };
+
+        // the year's mod.rs gets the day's module (rustfmt puts it in order)
+        let year_mod = year_mod + &format!("pub mod day{:02};\n", self.day);
+        // the registry gets the solution, so it can be found at runtime
+        let days_mod = insert_registry_entry(&days_mod, self.year, self.day, &self.solution_path())
+            .ok_or_else(|| ScaffoldError::MissingRegistryMarker(days_mod_path.clone()))?;
+        // answers aren't known until they're submitted
+        let answers = read_file(&answers_path)? + &answers_entry(self.year, self.day);
+
+        write_file(&year_mod_path, &year_mod)?;
+        write_file(&days_mod_path, &days_mod)?;
+        write_file(&answers_path, &answers)
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(path).map_err(|source| ScaffoldError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+    fs::write(path, contents).map_err(|source| ScaffoldError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+// "pub mod y2022;" after the last year before it
+fn insert_year_module(days_mod: &str, year: u16) -> String {
+    let declaration = format!("pub mod y{year};");
+    let mut lines: Vec<&str> = days_mod.lines().collect();
+
+    let year_lines: Vec<usize> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| line.starts_with("pub mod y"))
+        .map(|(idx, _)| idx)
+        .collect();
+    let position = year_lines
+        .iter()
+        .find(|idx| lines[**idx] > declaration.as_str())
+        .copied()
+        .or(year_lines.last().map(|idx| idx + 1))
+        .unwrap_or(0);
+
+    lines.insert(position, &declaration);
+
+    lines.join("\n") + "\n"
+}
+
+// registry lines look like "        &y2022::day23_performance::Day23Performance,"
+fn registry_entry_day(line: &str) -> Option<(u16, u8)> {
+    let path = line.trim().strip_prefix("&y")?;
+    let (year, rest) = path.split_once("::day")?;
+
+    Some((year.parse().ok()?, rest.get(..2)?.parse().ok()?))
+}
+
+// keeps the registry oldest first, so past years land before the current one
+fn insert_registry_entry(
+    days_mod: &str,
+    year: u16,
+    day: u8,
+    solution_path: &str,
+) -> Option<String> {
+    let entry = format!("        &{solution_path},");
+    let mut lines: Vec<&str> = days_mod.lines().collect();
+
+    let position = lines.iter().position(|line| {
+        *line == REGISTRY_MARKER
+            || registry_entry_day(line).is_some_and(|entry_day| entry_day > (year, day))
+    })?;
+
+    lines.insert(position, &entry);
+
+    Some(lines.join("\n") + "\n")
+}
+
+fn answers_entry(year: u16, day: u8) -> String {
+    format!(
+        "\n[{year}.{day:02}]\npart1 = {{ status = \"unknown\" }}\npart2 = {{ status = \"unknown\" }}\n"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::temp_dir::TempDir;
+
+    const DAYS_MOD: &str = "use crate::solution::DynSolution;
+
+pub mod y2022;
+pub mod y2023;
+
+pub fn registry() -> Vec<&'static dyn DynSolution> {
+    vec![
+        &y2022::day23::Day23,
+        &y2022::day23_performance::Day23Performance,
+        &y2023::day01::Day01,
+        // new days are added above this line by 00_generate
+    ]
+}
+";
+
+    fn temp_repo(name: &str) -> TempDir {
+        let root = TempDir::new(&format!("scaffold_{name}"));
+
+        fs::create_dir_all(root.join("src/days/y2022")).unwrap();
+        fs::create_dir_all(root.join("src/days/y2023")).unwrap();
+        fs::write(root.join("src/days/mod.rs"), DAYS_MOD).unwrap();
+        fs::write(root.join("src/days/y2022/mod.rs"), "pub mod day23;\n").unwrap();
+        fs::write(root.join("src/days/y2023/mod.rs"), "pub mod day01;\n").unwrap();
+        fs::write(root.join("src/days/y2023/day01.rs"), "").unwrap();
+        fs::write(root.join("answers.toml"), "").unwrap();
+
+        root
+    }
+
+    fn args(args: &str) -> Vec<String> {
+        args.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_generate_options() {
+        assert_eq!(GenerateOptions::from_args(args("")), Ok(None));
+
+        assert_eq!(
+            GenerateOptions::from_args(args("--day 8")),
+            Ok(Some(GenerateOptions {
+                year: 2023,
+                day: 8,
+                layout: Layout::Single,
+                puzzle_html: None,
+                editor: None,
+                dry_run: false,
+                session_file: None,
+                refresh_puzzle: false,
+            }))
+        );
+
+        assert_eq!(
+            GenerateOptions::from_args(args(
+                "--year 2022 --day 4 --layout split --puzzle-html 4.html --editor vim --dry-run --session-file s --refresh-puzzle"
+            )),
+            Ok(Some(GenerateOptions {
+                year: 2022,
+                day: 4,
+                layout: Layout::Split,
+                puzzle_html: Some(PathBuf::from("4.html")),
+                editor: Some("vim".to_string()),
+                dry_run: true,
+                session_file: Some(PathBuf::from("s")),
+                refresh_puzzle: true,
+            }))
+        );
+
+        // --no-open wins, even alongside an editor
+        let options = GenerateOptions::from_args(args("--day 4 --editor vim --no-open"));
+        assert_eq!(options.unwrap().unwrap().editor, None);
+    }
+
+    #[test]
+    fn test_invalid_generate_options() {
+        assert!(GenerateOptions::from_args(args("--year 2022")).is_err());
+        assert!(GenerateOptions::from_args(args("--day")).is_err());
+        assert!(GenerateOptions::from_args(args("--day 26")).is_err());
+        assert!(GenerateOptions::from_args(args("--day 0")).is_err());
+        assert!(GenerateOptions::from_args(args("--day 4 --layout both")).is_err());
+        assert!(GenerateOptions::from_args(args("--day 4 --open")).is_err());
+    }
+
+    #[test]
+    fn test_files() {
+        let files = Scaffold::new(2022, 4, Layout::Single).files();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].0, PathBuf::from("src/days/y2022/day04.rs"));
+        assert!(files[0].1.contains("pub struct Day04;"));
+        assert!(files[0].1.contains("const YEAR: u16 = 2022;"));
+        assert!(files[0].1.contains("const DAY: u8 = 4;"));
+
+        let files = Scaffold::new(2023, 12, Layout::Split).files();
+        let paths: Vec<&Path> = files.iter().map(|(path, _)| path.as_path()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                Path::new("src/days/y2023/day12/mod.rs"),
+                Path::new("src/days/y2023/day12/part1.rs"),
+                Path::new("src/days/y2023/day12/part2.rs"),
+            ]
+        );
+        assert!(files[0].1.contains("pub struct Day12;"));
+        assert!(files[0].1.contains("const YEAR: u16 = 2023;"));
+        assert!(files[0].1.contains("const DAY: u8 = 12;"));
+    }
+
+    #[test]
+    fn test_example_tests() {
+        let examples = vec![
+            Example {
+                part: 1,
+                input: "1abc2\ntreb7uchet".to_string(),
+                answer: Some("142".to_string()),
+            },
+            Example {
+                part: 2,
+                input: "    [D]\n\"a\" \\ b".to_string(),
+                answer: Some("MCD".to_string()),
+            },
+        ];
+
+        let files = Scaffold::new(2022, 5, Layout::Single)
+            .with_examples(examples.clone())
+            .files();
+        let code = &files[0].1;
+        assert!(code.contains("    fn part1(")); // template's solution is kept
+        assert!(!code.contains("// #[cfg(test)]"));
+        assert!(code.ends_with(
+            r#"
+    #[test]
+    fn test_part1_example() {
+        let example_input = "
+1abc2
+treb7uchet"
+            .trim();
+
+        assert_eq!(Day05::part1(&Day05::parse(example_input).unwrap()), 142);
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let example_input = "
+    [D]
+\"a\" \\ b"
+            .trim_matches('\n');
+
+        assert_eq!(Day05::part2(&Day05::parse(example_input).unwrap()).to_string(), "MCD");
+    }
+}
+"#
+        ));
+
+        // tests go in mod.rs for split days
+        let files = Scaffold::new(2023, 8, Layout::Split)
+            .with_examples(examples.clone())
+            .files();
+        assert!(files[0].1.contains("fn test_part2_example()"));
+
+        // a second example for a part doesn't clash with the first
+        let mut two_examples = examples.clone();
+        two_examples.push(examples[1].clone());
+        let files = Scaffold::new(2023, 8, Layout::Single)
+            .with_examples(two_examples)
+            .files();
+        assert!(files[0].1.contains("fn test_part2_example()"));
+        assert!(files[0].1.contains("fn test_part2_example_2()"));
+
+        // examples without answers leave the template as is
+        let examples = vec![Example {
+            part: 1,
+            input: "1".to_string(),
+            answer: None,
+        }];
+        let files = Scaffold::new(2023, 8, Layout::Single)
+            .with_examples(examples)
+            .files();
+        assert!(files[0].1.contains("// #[cfg(test)]"));
+    }
+
+    #[test]
+    fn test_insert_registry_entry() {
+        let registry = insert_registry_entry(DAYS_MOD, 2022, 4, "y2022::day04::Day04").unwrap();
+        assert!(registry.contains("        &y2022::day04::Day04,\n        &y2022::day23::Day23,\n"));
+
+        // after every variant of the same year
+        let registry = insert_registry_entry(DAYS_MOD, 2022, 25, "y2022::day25::Day25").unwrap();
+        assert!(registry.contains(
+            "Day23Performance,\n        &y2022::day25::Day25,\n        &y2023::day01::Day01,\n"
+        ));
+
+        let registry = insert_registry_entry(DAYS_MOD, 2023, 2, "y2023::day02::Day02").unwrap();
+        assert!(registry.contains(&format!("        &y2023::day02::Day02,\n{REGISTRY_MARKER}")));
+    }
+
+    #[test]
+    fn test_insert_year_module() {
+        let days_mod = insert_year_module(DAYS_MOD, 2021);
+        assert!(days_mod.contains("pub mod y2021;\npub mod y2022;\npub mod y2023;\n"));
+
+        let days_mod = insert_year_module(DAYS_MOD, 2024);
+        assert!(days_mod.contains("pub mod y2022;\npub mod y2023;\npub mod y2024;\n"));
+    }
+
+    #[test]
+    fn test_create_past_year() {
+        let root = temp_repo("past_year");
+
+        let touched = Scaffold::new(2021, 2, Layout::Single)
+            .create(root.path())
+            .unwrap();
+        assert_eq!(
+            touched,
+            vec![
+                (root.join("src/days/y2021/day02.rs"), FileChange::Create),
+                (root.join("src/days/y2021/mod.rs"), FileChange::Create),
+                (root.join("src/days/mod.rs"), FileChange::Modify),
+                (root.join("answers.toml"), FileChange::Modify),
+            ]
+        );
+
+        // new year module, registered before the later years
+        let days_mod = fs::read_to_string(root.join("src/days/mod.rs")).unwrap();
+        assert!(days_mod.contains("pub mod y2021;\npub mod y2022;"));
+        assert!(days_mod.contains("        &y2021::day02::Day02,\n        &y2022::day23::Day23,"));
+        assert_eq!(
+            fs::read_to_string(root.join("src/days/y2021/mod.rs")).unwrap(),
+            "pub mod day02;\n"
+        );
+
+        let answers = fs::read_to_string(root.join("answers.toml")).unwrap();
+        assert!(answers.contains("[2021.02]"));
+    }
+
+    #[test]
+    fn test_plan_writes_nothing() {
+        let root = temp_repo("plan");
+        let scaffold = Scaffold::new(2023, 2, Layout::Split);
+
+        let plan = scaffold.plan(root.path()).unwrap();
+        assert_eq!(plan.len(), 6);
+        assert_eq!(
+            plan[3],
+            (root.join("src/days/y2023/mod.rs"), FileChange::Modify)
+        );
+
+        assert!(!root.join("src/days/y2023/day02").exists());
+        assert_eq!(fs::read_to_string(root.join("answers.toml")).unwrap(), "");
+        assert_eq!(scaffold.create(root.path()).unwrap(), plan);
+    }
+
+    #[test]
+    fn test_missing_registry_marker() {
+        let root = temp_repo("marker");
+        fs::write(root.join("src/days/mod.rs"), "pub mod y2023;\n").unwrap();
+
+        assert!(matches!(
+            Scaffold::new(2023, 2, Layout::Single).create(root.path()),
+            Err(ScaffoldError::MissingRegistryMarker(_))
+        ));
+    }
+
+    #[test]
+    fn test_create_split() {
+        let root = temp_repo("split");
+
+        Scaffold::new(2023, 2, Layout::Split)
+            .create(root.path())
+            .unwrap();
+        assert!(root.join("src/days/y2023/day02/part2.rs").exists());
+        assert_eq!(
+            fs::read_to_string(root.join("src/days/y2023/mod.rs")).unwrap(),
+            "pub mod day01;\npub mod day02;\n"
+        );
+
+        // already exists in either layout
+        assert!(matches!(
+            Scaffold::new(2023, 2, Layout::Single).create(root.path()),
+            Err(ScaffoldError::AlreadyExists(path)) if path == root.join("src/days/y2023/day02")
+        ));
+        assert!(matches!(
+            Scaffold::new(2023, 1, Layout::Split).create(root.path()),
+            Err(ScaffoldError::AlreadyExists(_))
+        ));
+    }
+}
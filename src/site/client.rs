@@ -0,0 +1,354 @@
+// every request to adventofcode.com goes through here, so they're all identified & spaced out
+use crate::site::session::{is_login_response, load_session, SessionError};
+use crate::site::{base_url_from_env, SiteError};
+use reqwest::blocking::{Client, RequestBuilder};
+use reqwest::header::LOCATION;
+use reqwest::redirect::Policy;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// the site asks automated tools to say who they are
+pub const USER_AGENT: &str = concat!(
+    "github.com/jyelewis/advent-of-code-2023 (",
+    env!("CARGO_PKG_NAME"),
+    " ",
+    env!("CARGO_PKG_VERSION"),
+    ")"
+);
+
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(5);
+pub const DEFAULT_RETRIES: u32 = 3;
+pub const DEFAULT_BACKOFF: Duration = Duration::from_secs(1);
+
+// when the last request was sent, shared by every run from this checkout
+pub fn default_state_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("target/aoc_last_request")
+}
+
+pub struct SiteClient {
+    base_url: String,
+    session: Result<String, SessionError>,
+    http: Client,
+    min_interval: Duration,
+    // None keeps the last request time in memory only
+    state_path: Option<PathBuf>,
+    last_request: Mutex<Option<SystemTime>>,
+    // gets are retried on server errors (5xx) & failed connections, waiting backoff, 2x backoff, ...
+    retries: u32,
+    backoff: Duration,
+}
+
+impl SiteClient {
+    pub fn new(base_url: &str, session: Result<String, SessionError>) -> SiteClient {
+        SiteClient {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            http: Client::builder()
+                .user_agent(USER_AGENT)
+                // redirects are how the site says the session's no good, so they're not followed
+                .redirect(Policy::none())
+                .build()
+                .expect("Failed to build http client"),
+            min_interval: DEFAULT_MIN_INTERVAL,
+            state_path: Some(default_state_path()),
+            last_request: Mutex::new(None),
+            retries: DEFAULT_RETRIES,
+            backoff: DEFAULT_BACKOFF,
+        }
+    }
+
+    // AOC_BASE_URL, & the session from the file given, AOC_SESSION_TOKEN or the config dir
+    pub fn from_env(session_file: Option<&Path>) -> SiteClient {
+        SiteClient::new(&base_url_from_env(), load_session(session_file))
+    }
+
+    pub fn with_min_interval(mut self, min_interval: Duration) -> SiteClient {
+        self.min_interval = min_interval;
+        self
+    }
+
+    pub fn with_state_path(mut self, state_path: Option<PathBuf>) -> SiteClient {
+        self.state_path = state_path;
+        self
+    }
+
+    pub fn with_retries(mut self, retries: u32, backoff: Duration) -> SiteClient {
+        self.retries = retries;
+        self.backoff = backoff;
+        self
+    }
+
+    // for requests that are per account, so pointless to send without a session
+    pub fn require_session(&self) -> Result<(), SiteError> {
+        match &self.session {
+            Ok(_) => Ok(()),
+            Err(err) => Err(SiteError::Session(err.clone())),
+        }
+    }
+
+    pub fn get(&self, path: &str) -> Result<String, SiteError> {
+        self.send(path, self.retries, |http, url| http.get(url))
+    }
+
+    // sent once only, a timeout could be an answer the site has already taken & a second
+    // submission of a wrong one means waiting out another lockout
+    pub fn post_form(&self, path: &str, form: &[(&str, String)]) -> Result<String, SiteError> {
+        self.send(path, 0, |http, url| http.post(url).form(form))
+    }
+
+    fn send(
+        &self,
+        path: &str,
+        retries: u32,
+        build: impl Fn(&Client, &str) -> RequestBuilder,
+    ) -> Result<String, SiteError> {
+        let url = format!("{}{path}", self.base_url);
+
+        let mut attempt = 0;
+        loop {
+            self.wait_for_turn();
+
+            let mut request = build(&self.http, &url);
+            if let Ok(session_token) = &self.session {
+                request = request.header("Cookie", format!("session={session_token}"));
+            }
+            let request_error = |err: reqwest::Error| SiteError::Request {
+                url: url.clone(),
+                message: err.to_string(),
+            };
+
+            let error = match request.send() {
+                Ok(response) => {
+                    let status = response.status();
+                    let location = response
+                        .headers()
+                        .get(LOCATION)
+                        .and_then(|location| location.to_str().ok())
+                        .map(str::to_string);
+                    let body = response.text().map_err(request_error)?;
+
+                    if status.is_success() {
+                        return Ok(body);
+                    }
+                    if is_login_response(status.as_u16(), location.as_deref(), &body) {
+                        return Err(match &self.session {
+                            Ok(_) => SiteError::SessionExpired,
+                            Err(err) => SiteError::Session(err.clone()),
+                        });
+                    }
+
+                    let error = SiteError::Status {
+                        url: url.clone(),
+                        status: status.as_u16(),
+                    };
+                    // 4xx won't get any better by asking again
+                    if !status.is_server_error() {
+                        return Err(error);
+                    }
+                    error
+                }
+                Err(err) => request_error(err),
+            };
+
+            if attempt >= retries {
+                return Err(error);
+            }
+            std::thread::sleep(self.backoff * 2u32.pow(attempt));
+            attempt += 1;
+        }
+    }
+
+    // sleeps until min_interval has passed since the last request (from this run or a previous one)
+    fn wait_for_turn(&self) {
+        let mut last_request = self.last_request.lock().unwrap();
+
+        let previous = [*last_request, self.read_state()]
+            .into_iter()
+            .flatten()
+            .max();
+        if let Some(previous) = previous {
+            let elapsed = SystemTime::now()
+                .duration_since(previous)
+                .unwrap_or_default();
+            if elapsed < self.min_interval {
+                std::thread::sleep(self.min_interval - elapsed);
+            }
+        }
+
+        let now = SystemTime::now();
+        *last_request = Some(now);
+        self.write_state(now);
+    }
+
+    fn read_state(&self) -> Option<SystemTime> {
+        let millis: u64 = fs::read_to_string(self.state_path.as_ref()?)
+            .ok()?
+            .trim()
+            .parse()
+            .ok()?;
+
+        Some(UNIX_EPOCH + Duration::from_millis(millis))
+    }
+
+    fn write_state(&self, time: SystemTime) {
+        let Some(state_path) = &self.state_path else {
+            return;
+        };
+        let millis = time
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+
+        // best effort, a missed write only means the next run might not wait
+        if let Some(parent) = state_path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        let _ = fs::write(state_path, millis.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::site::test_server::{test_client, Response, TestServer};
+    use crate::temp_dir::TempDir;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Instant;
+
+    #[test]
+    fn test_headers() {
+        let server = TestServer::start(|_| Response::ok("ok"));
+        let client = test_client(&server);
+
+        assert_eq!(client.get("/2023/day/1").unwrap(), "ok");
+        assert_eq!(
+            client
+                .post_form("/2023/day/1/answer", &[("level", "1".to_string())])
+                .unwrap(),
+            "ok"
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests[0].header("User-Agent"), Some(USER_AGENT));
+        assert_eq!(requests[0].header("Cookie"), Some("session=abc"));
+        assert_eq!(requests[1].method, "POST");
+        assert_eq!(requests[1].body, "level=1");
+
+        // no session, no cookie
+        let client = SiteClient::new(
+            server.url(),
+            Err(SessionError::Missing { config_path: None }),
+        )
+        .with_min_interval(Duration::ZERO)
+        .with_state_path(None);
+        client.get("/2023/day/1").unwrap();
+        assert_eq!(server.requests()[2].header("Cookie"), None);
+    }
+
+    #[test]
+    fn test_min_interval_across_runs() {
+        let server = TestServer::start(|_| Response::ok("ok"));
+        let dir = TempDir::new("last_request");
+        let state_path = dir.join("last_request");
+
+        let client = || {
+            test_client(&server)
+                .with_min_interval(Duration::from_millis(200))
+                .with_state_path(Some(state_path.clone()))
+        };
+
+        let start = Instant::now();
+        client().get("/").unwrap();
+        assert!(start.elapsed() < Duration::from_millis(200));
+
+        // a new client (i.e. the next run) still waits for the interval
+        client().get("/").unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        assert!(state_path.exists());
+    }
+
+    #[test]
+    fn test_retry_server_errors() {
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let server = {
+            let attempts = attempts.clone();
+            TestServer::start(move |_| match attempts.fetch_add(1, Ordering::SeqCst) {
+                0 | 1 => Response::status(503, "busy"),
+                _ => Response::ok("ok"),
+            })
+        };
+
+        let client = test_client(&server).with_retries(2, Duration::from_millis(5));
+        assert_eq!(client.get("/").unwrap(), "ok");
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+        // out of retries
+        attempts.store(0, Ordering::SeqCst);
+        let client = test_client(&server).with_retries(1, Duration::from_millis(5));
+        assert!(matches!(
+            client.get("/"),
+            Err(SiteError::Status { status: 503, .. })
+        ));
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_posts_arent_retried() {
+        let server = TestServer::start(|_| Response::status(503, "busy"));
+        let client = test_client(&server).with_retries(3, Duration::from_millis(5));
+
+        assert!(matches!(
+            client.post_form("/2023/day/1/answer", &[("level", "1".to_string())]),
+            Err(SiteError::Status { status: 503, .. })
+        ));
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn test_session_expired() {
+        let server = TestServer::start(|request| match request.path.as_str() {
+            "/2023/day/1/input" => Response::status(
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+            ),
+            _ => Response::status(302, "").with_header("Location", "/auth/login"),
+        });
+        let client = test_client(&server);
+
+        assert!(matches!(
+            client.get("/2023/day/1/input"),
+            Err(SiteError::SessionExpired)
+        ));
+        assert!(matches!(
+            client.post_form("/2023/day/1/answer", &[]),
+            Err(SiteError::SessionExpired)
+        ));
+
+        // without a session it's the missing session that's the problem
+        let missing = SessionError::Missing { config_path: None };
+        let client = SiteClient::new(server.url(), Err(missing.clone())).with_state_path(None);
+        assert!(matches!(
+            client.get("/2023/day/1/input"),
+            Err(SiteError::Session(err)) if err == missing
+        ));
+        assert!(matches!(
+            client.require_session(),
+            Err(SiteError::Session(err)) if err == missing
+        ));
+    }
+
+    #[test]
+    fn test_client_errors_arent_retried() {
+        let server = TestServer::start(|_| Response::status(404, "Not found"));
+        let client = test_client(&server).with_retries(3, Duration::from_millis(5));
+
+        assert!(matches!(
+            client.get("/"),
+            Err(SiteError::Status { status: 404, .. })
+        ));
+        assert_eq!(server.requests().len(), 1);
+    }
+}
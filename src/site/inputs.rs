@@ -0,0 +1,170 @@
+use crate::input::InputSource;
+use crate::site::client::SiteClient;
+use crate::site::{offline_from_env, SiteError};
+use std::fs;
+use std::path::Path;
+
+// puzzle inputs never change, so anything already in the inputs dir is served from there
+// and never downloaded again
+pub struct InputCache {
+    inputs: InputSource,
+    client: SiteClient,
+    offline: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    Cached(String),
+    Downloaded(String),
+}
+
+impl Fetched {
+    pub fn contents(&self) -> &str {
+        match self {
+            Fetched::Cached(contents) | Fetched::Downloaded(contents) => contents,
+        }
+    }
+}
+
+impl InputCache {
+    pub fn new(inputs: InputSource, client: SiteClient, offline: bool) -> InputCache {
+        InputCache {
+            inputs,
+            client,
+            offline,
+        }
+    }
+
+    // AOC_BASE_URL, AOC_OFFLINE & the session (see session.rs)
+    pub fn from_env(inputs: InputSource, session_file: Option<&Path>) -> InputCache {
+        InputCache::new(
+            inputs,
+            SiteClient::from_env(session_file),
+            offline_from_env(),
+        )
+    }
+
+    pub fn client(&self) -> &SiteClient {
+        &self.client
+    }
+
+    pub fn is_cached(&self, year: u16, day: u8) -> bool {
+        self.inputs.path(year, day).exists()
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Result<Fetched, SiteError> {
+        let path = self.inputs.path(year, day);
+
+        if let Ok(contents) = fs::read_to_string(&path) {
+            return Ok(Fetched::Cached(contents));
+        }
+        if self.offline {
+            return Err(SiteError::Offline { path });
+        }
+
+        let contents = self.download(year, day)?;
+
+        fs::create_dir_all(self.inputs.dir())
+            .and_then(|_| fs::write(&path, &contents))
+            .map_err(|source| SiteError::Io { path, source })?;
+
+        Ok(Fetched::Downloaded(contents))
+    }
+
+    fn download(&self, year: u16, day: u8) -> Result<String, SiteError> {
+        self.client.require_session()?;
+        self.client.get(&format!("/{year}/day/{day}/input"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::site::session::SessionError;
+    use crate::site::test_server::{test_client, Response, TestServer};
+    use crate::temp_dir::TempDir;
+
+    fn temp_inputs(name: &str) -> (TempDir, InputSource) {
+        let root = TempDir::new(&format!("cache_{name}"));
+        let inputs = InputSource::new(root.path(), None);
+
+        (root, inputs)
+    }
+
+    fn input_server() -> TestServer {
+        TestServer::start(|request| match request.path.as_str() {
+            "/2023/day/1/input" => Response::ok("1abc2\n"),
+            _ => Response::status(404, "Not found"),
+        })
+    }
+
+    #[test]
+    fn test_download_then_cache() {
+        let server = input_server();
+        let (_root, inputs) = temp_inputs("download");
+        let cache = InputCache::new(inputs.clone(), test_client(&server), false);
+
+        assert!(!cache.is_cached(2023, 1));
+        assert_eq!(
+            cache.get(2023, 1).unwrap(),
+            Fetched::Downloaded("1abc2\n".to_string())
+        );
+        assert_eq!(fs::read_to_string(inputs.path(2023, 1)).unwrap(), "1abc2\n");
+
+        // second time round it's served from disk, without another request
+        assert!(cache.is_cached(2023, 1));
+        assert_eq!(
+            cache.get(2023, 1).unwrap(),
+            Fetched::Cached("1abc2\n".to_string())
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].header("Cookie"), Some("session=abc"));
+    }
+
+    #[test]
+    fn test_offline() {
+        let server = input_server();
+        let (_root, inputs) = temp_inputs("offline");
+        let cache = InputCache::new(inputs.clone(), test_client(&server), true);
+
+        assert!(matches!(
+            cache.get(2023, 1),
+            Err(SiteError::Offline { path }) if path == inputs.path(2023, 1)
+        ));
+
+        // previously fetched inputs are still served
+        fs::write(inputs.path(2023, 1), "cached").unwrap();
+        assert_eq!(
+            cache.get(2023, 1).unwrap(),
+            Fetched::Cached("cached".to_string())
+        );
+
+        assert!(server.requests().is_empty());
+    }
+
+    #[test]
+    fn test_download_errors() {
+        let server = input_server();
+        let (_root, inputs) = temp_inputs("errors");
+
+        let client = SiteClient::new(
+            server.url(),
+            Err(SessionError::Missing { config_path: None }),
+        )
+        .with_state_path(None);
+        let cache = InputCache::new(inputs.clone(), client, false);
+        assert!(matches!(cache.get(2023, 1), Err(SiteError::Session(_))));
+
+        let cache = InputCache::new(inputs.clone(), test_client(&server), false);
+        assert!(matches!(
+            cache.get(2023, 2),
+            Err(SiteError::Status { status: 404, .. })
+        ));
+
+        // failed downloads aren't cached
+        assert!(!cache.is_cached(2023, 2));
+    }
+}
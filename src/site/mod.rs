@@ -0,0 +1,66 @@
+// talking to adventofcode.com, everything here can be pointed at a local stand-in server instead
+use crate::site::session::SessionError;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::PathBuf;
+
+pub mod client;
+pub mod inputs;
+pub mod puzzle;
+pub mod session;
+pub mod submit;
+#[cfg(test)]
+mod test_server;
+
+pub const AOC_URL: &str = "https://adventofcode.com";
+
+// i.e. AOC_BASE_URL=http://127.0.0.1:8080 to use a stand-in server
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+pub const SESSION_TOKEN_ENV: &str = "AOC_SESSION_TOKEN";
+// any value other than "0" / "" turns on offline mode
+pub const OFFLINE_ENV: &str = "AOC_OFFLINE";
+
+pub fn base_url_from_env() -> String {
+    std::env::var(BASE_URL_ENV).unwrap_or_else(|_| AOC_URL.to_string())
+}
+
+pub fn offline_from_env() -> bool {
+    std::env::var(OFFLINE_ENV).is_ok_and(|value| !value.is_empty() && value != "0")
+}
+
+#[derive(Debug)]
+pub enum SiteError {
+    // offline mode & nothing cached
+    Offline { path: PathBuf },
+    // no usable session token
+    Session(SessionError),
+    // the site sent us to its login page
+    SessionExpired,
+    Status { url: String, status: u16 },
+    Request { url: String, message: String },
+    Io { path: PathBuf, source: io::Error },
+}
+
+impl Display for SiteError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SiteError::Offline { path } => write!(
+                f,
+                "Offline and {} hasn't been downloaded yet",
+                path.display()
+            ),
+            SiteError::Session(err) => write!(f, "{err}"),
+            SiteError::SessionExpired => write!(
+                f,
+                "The session token wasn't accepted, it's probably expired, log in again & update it"
+            ),
+            SiteError::Status { url, status } => write!(f, "{url} responded with {status}"),
+            SiteError::Request { url, message } => write!(f, "Request to {url} failed: {message}"),
+            SiteError::Io { path, source } => {
+                write!(f, "Failed to write {}: {source}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for SiteError {}
@@ -0,0 +1,31 @@
+use crate::site::client::SiteClient;
+use crate::site::SiteError;
+
+// the puzzle's page, part 2's description is only on it once part 1 is solved (needs a session)
+pub fn fetch_puzzle(client: &SiteClient, year: u16, day: u8) -> Result<String, SiteError> {
+    client.get(&format!("/{year}/day/{day}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::site::test_server::{test_client, Response, TestServer};
+
+    #[test]
+    fn test_fetch_puzzle() {
+        let server = TestServer::start(|request| match request.path.as_str() {
+            "/2023/day/1" => Response::ok("<article><h2>--- Day 1 ---</h2></article>"),
+            _ => Response::status(404, "Not found"),
+        });
+        let client = test_client(&server);
+
+        assert_eq!(
+            fetch_puzzle(&client, 2023, 1).unwrap(),
+            "<article><h2>--- Day 1 ---</h2></article>"
+        );
+        assert!(matches!(
+            fetch_puzzle(&client, 2023, 2),
+            Err(SiteError::Status { status: 404, .. })
+        ));
+    }
+}
@@ -0,0 +1,231 @@
+// the session cookie from a logged in browser, the first of these that's set wins:
+//   --session-file <file>
+//   AOC_SESSION_TOKEN
+//   <config dir>/advent-of-code/session, i.e. ~/.config/advent-of-code/session
+use crate::site::SESSION_TOKEN_ENV;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SessionSource {
+    File(PathBuf),
+    Env,
+}
+
+impl Display for SessionSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SessionSource::File(path) => write!(f, "{}", path.display()),
+            SessionSource::Env => write!(f, "{SESSION_TOKEN_ENV}"),
+        }
+    }
+}
+
+// cloneable (io errors are kept as their message) so a client can hand it out on every request
+#[derive(Debug, Clone, PartialEq)]
+pub enum SessionError {
+    Missing {
+        config_path: Option<PathBuf>,
+    },
+    Unreadable {
+        path: PathBuf,
+        message: String,
+    },
+    Invalid {
+        source: SessionSource,
+        reason: String,
+    },
+}
+
+impl Display for SessionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SessionError::Missing { config_path } => {
+                write!(
+                    f,
+                    "No session token, set {SESSION_TOKEN_ENV}, pass --session-file"
+                )?;
+                match config_path {
+                    Some(config_path) => write!(f, " or save it to {}", config_path.display()),
+                    None => Ok(()),
+                }
+            }
+            SessionError::Unreadable { path, message } => {
+                write!(
+                    f,
+                    "Failed to read session token {}: {message}",
+                    path.display()
+                )
+            }
+            SessionError::Invalid { source, reason } => {
+                write!(f, "Session token from {source} {reason}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SessionError {}
+
+pub fn config_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|config_dir| config_dir.join("advent-of-code").join("session"))
+}
+
+pub fn load_session(session_file: Option<&Path>) -> Result<String, SessionError> {
+    resolve(
+        session_file,
+        std::env::var(SESSION_TOKEN_ENV).ok(),
+        config_path().as_deref(),
+    )
+}
+
+fn resolve(
+    session_file: Option<&Path>,
+    env_token: Option<String>,
+    config_path: Option<&Path>,
+) -> Result<String, SessionError> {
+    if let Some(session_file) = session_file {
+        let token = read(session_file)?;
+        return validate(&token, SessionSource::File(session_file.to_path_buf()));
+    }
+    if let Some(env_token) = env_token {
+        return validate(&env_token, SessionSource::Env);
+    }
+
+    // the config file is optional, only a file that's there but can't be read is an error
+    let missing = || SessionError::Missing {
+        config_path: config_path.map(Path::to_path_buf),
+    };
+    let config_path = config_path.ok_or_else(missing)?;
+    match read(config_path) {
+        Ok(token) => validate(&token, SessionSource::File(config_path.to_path_buf())),
+        Err(SessionError::Unreadable { .. }) if !config_path.exists() => Err(missing()),
+        Err(err) => Err(err),
+    }
+}
+
+fn read(path: &Path) -> Result<String, SessionError> {
+    std::fs::read_to_string(path).map_err(|err: io::Error| SessionError::Unreadable {
+        path: path.to_path_buf(),
+        message: err.to_string(),
+    })
+}
+
+// the cookie's value is a long hex string, copied from the browser with or without "session="
+fn validate(token: &str, source: SessionSource) -> Result<String, SessionError> {
+    let token = token.trim();
+    let token = token.strip_prefix("session=").unwrap_or(token);
+
+    let reason = if token.is_empty() {
+        "is empty"
+    } else if !token.chars().all(|char| char.is_ascii_hexdigit()) {
+        "isn't a hex string, copy the value of the session cookie"
+    } else {
+        return Ok(token.to_string());
+    };
+
+    Err(SessionError::Invalid {
+        source,
+        reason: reason.to_string(),
+    })
+}
+
+// what the site does with a session it doesn't accept
+// pages & answers redirect to the login page, inputs are a 400 asking to log in
+pub fn is_login_response(status: u16, location: Option<&str>, body: &str) -> bool {
+    match status {
+        300..=399 => location.is_some_and(|location| location.contains("/auth/login")),
+        400 => body.contains("Please log in"),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::temp_dir::TempDir;
+    use std::fs;
+
+    #[test]
+    fn test_resolve_order() {
+        let dir = TempDir::new("session_order");
+        let flag_file = dir.join("flag");
+        let config_file = dir.join("config");
+        fs::write(&flag_file, "abc123\n").unwrap();
+        fs::write(&config_file, "def456\n").unwrap();
+
+        assert_eq!(
+            resolve(
+                Some(&flag_file),
+                Some("aaa".to_string()),
+                Some(&config_file)
+            ),
+            Ok("abc123".to_string())
+        );
+        assert_eq!(
+            resolve(None, Some("aaa".to_string()), Some(&config_file)),
+            Ok("aaa".to_string())
+        );
+        assert_eq!(
+            resolve(None, None, Some(&config_file)),
+            Ok("def456".to_string())
+        );
+    }
+
+    #[test]
+    fn test_resolve_errors() {
+        let dir = TempDir::new("session_errors");
+        let missing_config = dir.join("nobody/session");
+        assert_eq!(
+            resolve(None, None, Some(&missing_config)),
+            Err(SessionError::Missing {
+                config_path: Some(missing_config.clone())
+            })
+        );
+        assert_eq!(
+            resolve(None, None, None),
+            Err(SessionError::Missing { config_path: None })
+        );
+
+        // an explicitly given file has to exist
+        assert!(matches!(
+            resolve(Some(&missing_config), None, None),
+            Err(SessionError::Unreadable { path, .. }) if path == missing_config
+        ));
+
+        assert_eq!(
+            resolve(None, Some(" \n".to_string()), None),
+            Err(SessionError::Invalid {
+                source: SessionSource::Env,
+                reason: "is empty".to_string()
+            })
+        );
+        let error = resolve(None, Some("not a token".to_string()), None).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Session token from AOC_SESSION_TOKEN isn't a hex string, copy the value of the session cookie"
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(
+            validate("session=53616c7465645f5f\n", SessionSource::Env),
+            Ok("53616c7465645f5f".to_string())
+        );
+    }
+
+    #[test]
+    fn test_is_login_response() {
+        assert!(is_login_response(302, Some("/auth/login"), ""));
+        assert!(is_login_response(
+            400,
+            None,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"
+        ));
+
+        assert!(!is_login_response(302, Some("/2023/day/1"), ""));
+        assert!(!is_login_response(400, None, "Bad request"));
+        assert!(!is_login_response(200, None, "Please log in"));
+    }
+}
@@ -0,0 +1,288 @@
+use crate::answer::Answer;
+use crate::answers::{AnswerStatus, KnownAnswer};
+use crate::html::text;
+use crate::site::client::SiteClient;
+use crate::site::SiteError;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use std::time::Duration;
+
+#[derive(Debug, PartialEq)]
+pub enum SubmitOutcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    // answered too recently, nothing was checked
+    RateLimited { wait: Option<Duration> },
+    // the part's already been solved (or part 1 hasn't been yet)
+    AlreadySolved,
+    // the page's text, when it's none of the above
+    Unrecognised(String),
+}
+
+impl SubmitOutcome {
+    // what the answer store should now say about the answer, if anything
+    pub fn known_answer(&self, answer: &Answer) -> Option<KnownAnswer> {
+        let status = match self {
+            SubmitOutcome::Correct => AnswerStatus::Verified,
+            SubmitOutcome::Wrong | SubmitOutcome::TooHigh | SubmitOutcome::TooLow => {
+                AnswerStatus::KnownWrong
+            }
+            _ => return None,
+        };
+
+        Some(KnownAnswer {
+            answer: Some(answer.to_string()),
+            status,
+        })
+    }
+}
+
+impl Display for SubmitOutcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitOutcome::Correct => write!(f, "That's the right answer!"),
+            SubmitOutcome::Wrong => write!(f, "That's not the right answer"),
+            SubmitOutcome::TooHigh => write!(f, "That's not the right answer, it's too high"),
+            SubmitOutcome::TooLow => write!(f, "That's not the right answer, it's too low"),
+            SubmitOutcome::RateLimited { wait: Some(wait) } => {
+                write!(f, "Answered too recently, wait {}s", wait.as_secs())
+            }
+            SubmitOutcome::RateLimited { wait: None } => write!(f, "Answered too recently"),
+            SubmitOutcome::AlreadySolved => {
+                write!(f, "Not solving the right level, already solved?")
+            }
+            SubmitOutcome::Unrecognised(message) => write!(f, "Unrecognised response: {message}"),
+        }
+    }
+}
+
+// the answer page's <article> holds a sentence or two describing the outcome
+pub fn parse_response(html: &str) -> SubmitOutcome {
+    let message = text(html);
+
+    if message.contains("That's the right answer") {
+        SubmitOutcome::Correct
+    } else if message.contains("That's not the right answer") {
+        if message.contains("too high") {
+            SubmitOutcome::TooHigh
+        } else if message.contains("too low") {
+            SubmitOutcome::TooLow
+        } else {
+            SubmitOutcome::Wrong
+        }
+    } else if message.contains("You gave an answer too recently") {
+        SubmitOutcome::RateLimited {
+            wait: parse_wait(&message),
+        }
+    } else if message.contains("You don't seem to be solving the right level") {
+        SubmitOutcome::AlreadySolved
+    } else {
+        SubmitOutcome::Unrecognised(message.split_whitespace().collect::<Vec<&str>>().join(" "))
+    }
+}
+
+// "You have 1m 23s left to wait." -> 83s
+fn parse_wait(message: &str) -> Option<Duration> {
+    let start = message.find("You have ")? + "You have ".len();
+    let end = start + message[start..].find(" left to wait")?;
+
+    let seconds = message[start..end]
+        .split_whitespace()
+        .map(|amount| {
+            if let Some(minutes) = amount.strip_suffix('m') {
+                minutes.parse::<u64>().ok().map(|minutes| minutes * 60)
+            } else {
+                amount.strip_suffix('s')?.parse::<u64>().ok()
+            }
+        })
+        .sum::<Option<u64>>()?;
+
+    Some(Duration::from_secs(seconds))
+}
+
+pub struct Submitter {
+    client: SiteClient,
+}
+
+impl Submitter {
+    pub fn new(client: SiteClient) -> Submitter {
+        Submitter { client }
+    }
+
+    // AOC_BASE_URL & the session (see session.rs)
+    pub fn from_env(session_file: Option<&Path>) -> Submitter {
+        Submitter::new(SiteClient::from_env(session_file))
+    }
+
+    pub fn client(&self) -> &SiteClient {
+        &self.client
+    }
+
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &Answer,
+    ) -> Result<SubmitOutcome, SiteError> {
+        self.client.require_session()?;
+
+        let html = self.client.post_form(
+            &format!("/{year}/day/{day}/answer"),
+            &[("level", part.to_string()), ("answer", answer.to_string())],
+        )?;
+
+        // only the <article> has the outcome, the rest is the site's header & footer
+        let article = crate::html::inner_blocks(&html, "article")
+            .first()
+            .map_or(html.as_str(), |article| article);
+
+        Ok(parse_response(article))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::site::session::SessionError;
+    use crate::site::test_server::{test_client, Response, TestServer};
+
+    fn answer_page(message: &str) -> String {
+        format!(
+            "<html><body><header>Advent of Code</header><main><article><p>{message}</p></article></main></body></html>"
+        )
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            parse_response("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer."),
+            SubmitOutcome::Correct
+        );
+        assert_eq!(
+            parse_response("That's not the right answer.  If you're stuck, make sure you're using the full input data."),
+            SubmitOutcome::Wrong
+        );
+        assert_eq!(
+            parse_response("That's not the right answer; your answer is too high.  Please wait one minute before trying again."),
+            SubmitOutcome::TooHigh
+        );
+        assert_eq!(
+            parse_response("That's not the right answer; your answer is too low."),
+            SubmitOutcome::TooLow
+        );
+        assert_eq!(
+            parse_response("You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2023/day/1\">[Return to Day 1]</a>"),
+            SubmitOutcome::AlreadySolved
+        );
+        assert_eq!(
+            parse_response("Something\n  else"),
+            SubmitOutcome::Unrecognised("Something else".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_rate_limited() {
+        assert_eq!(
+            parse_response("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait."),
+            SubmitOutcome::RateLimited {
+                wait: Some(Duration::from_secs(83))
+            }
+        );
+        assert_eq!(
+            parse_response("You gave an answer too recently. You have 34s left to wait."),
+            SubmitOutcome::RateLimited {
+                wait: Some(Duration::from_secs(34))
+            }
+        );
+        assert_eq!(
+            parse_response("You gave an answer too recently."),
+            SubmitOutcome::RateLimited { wait: None }
+        );
+    }
+
+    #[test]
+    fn test_known_answer() {
+        assert_eq!(
+            SubmitOutcome::Correct.known_answer(&Answer::from(142u32)),
+            Some(KnownAnswer {
+                answer: Some("142".to_string()),
+                status: AnswerStatus::Verified,
+            })
+        );
+        assert_eq!(
+            SubmitOutcome::TooLow
+                .known_answer(&Answer::from(1u32))
+                .unwrap()
+                .status,
+            AnswerStatus::KnownWrong
+        );
+        assert_eq!(
+            SubmitOutcome::RateLimited { wait: None }.known_answer(&Answer::from(1u32)),
+            None
+        );
+        assert_eq!(
+            SubmitOutcome::AlreadySolved.known_answer(&Answer::from(1u32)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_submit() {
+        let server = TestServer::start(|request| {
+            let page = match request.body.as_str() {
+                "level=1&answer=142" => "That's the right answer!",
+                "level=2&answer=999" => "That's not the right answer; your answer is too high.",
+                _ => "You gave an answer too recently. You have 5s left to wait.",
+            };
+
+            Response::ok(&answer_page(page))
+        });
+        let submitter = Submitter::new(test_client(&server));
+
+        assert_eq!(
+            submitter.submit(2023, 1, 1, &Answer::from(142u32)).unwrap(),
+            SubmitOutcome::Correct
+        );
+        assert_eq!(
+            submitter.submit(2023, 1, 2, &Answer::from(999u32)).unwrap(),
+            SubmitOutcome::TooHigh
+        );
+        assert_eq!(
+            submitter.submit(2023, 1, 2, &Answer::from(1u32)).unwrap(),
+            SubmitOutcome::RateLimited {
+                wait: Some(Duration::from_secs(5))
+            }
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2023/day/1/answer");
+        assert_eq!(requests[0].header("Cookie"), Some("session=abc"));
+        assert_eq!(
+            requests[0].header("Content-Type"),
+            Some("application/x-www-form-urlencoded")
+        );
+    }
+
+    #[test]
+    fn test_submit_errors() {
+        let server = TestServer::start(|_| Response::status(500, "oops"));
+
+        let missing = SessionError::Missing { config_path: None };
+        let submitter =
+            Submitter::new(SiteClient::new(server.url(), Err(missing)).with_state_path(None));
+        assert!(matches!(
+            submitter.submit(2023, 1, 1, &Answer::from(142u32)),
+            Err(SiteError::Session(_))
+        ));
+
+        let submitter = Submitter::new(test_client(&server));
+        assert!(matches!(
+            submitter.submit(2023, 1, 1, &Answer::from(142u32)),
+            Err(SiteError::Status { status: 500, .. })
+        ));
+    }
+}
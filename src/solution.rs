@@ -0,0 +1,177 @@
+use crate::answer::Answer;
+use crate::input::load_input;
+use crate::parse::ParseError;
+use std::any::Any;
+
+// every day implements this, so tooling can run any day without knowing its bin name
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    // performance variants share a year & day with the original solution
+    const VARIANT: Option<&'static str> = None;
+
+    type Input;
+    // anything that converts into an Answer, i.e. u32, usize, i64, String
+    type Part1: Into<Answer>;
+    type Part2: Into<Answer>;
+
+    // a malformed input is reported with where it went wrong, rather than a panic
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Part1;
+    fn part2(input: &Self::Input) -> Self::Part2;
+}
+
+// type erased version of Solution, so days with different input & answer types can live in one list
+pub trait DynSolution {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+    fn variant(&self) -> Option<&'static str>;
+
+    // parsed input is handed back to part1/part2, which downcast it to the day's Input type
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+    fn part1(&self, parsed: &dyn Any) -> Answer;
+    fn part2(&self, parsed: &dyn Any) -> Answer;
+}
+
+impl<S: Solution> DynSolution for S
+where
+    S::Input: 'static,
+{
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn variant(&self) -> Option<&'static str> {
+        S::VARIANT
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn part1(&self, parsed: &dyn Any) -> Answer {
+        S::part1(downcast_input::<S>(parsed)).into()
+    }
+
+    fn part2(&self, parsed: &dyn Any) -> Answer {
+        S::part2(downcast_input::<S>(parsed)).into()
+    }
+}
+
+fn downcast_input<S: Solution>(parsed: &dyn Any) -> &S::Input
+where
+    S::Input: 'static,
+{
+    parsed
+        .downcast_ref::<S::Input>()
+        .expect("Parsed input came from a different solution")
+}
+
+// for tests & benches, panics with where the input is malformed
+pub fn parse_input<S: Solution>() -> S::Input {
+    let input = load_input(S::YEAR, S::DAY);
+
+    S::parse(&input).unwrap_or_else(|err| panic!("Invalid input, {err}"))
+}
+
+pub fn solve_part1<S: Solution>() -> S::Part1 {
+    S::part1(&parse_input::<S>())
+}
+
+pub fn solve_part2<S: Solution>() -> S::Part2 {
+    S::part2(&parse_input::<S>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::LineParser;
+
+    struct CountLines;
+
+    impl Solution for CountLines {
+        const YEAR: u16 = 2023;
+        const DAY: u8 = 1;
+
+        type Input = Vec<String>;
+        type Part1 = usize;
+        type Part2 = String;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            Ok(crate::input::lines(input).map(String::from).collect())
+        }
+
+        fn part1(input: &Self::Input) -> Self::Part1 {
+            input.len()
+        }
+
+        fn part2(input: &Self::Input) -> Self::Part2 {
+            input.join(",")
+        }
+    }
+
+    struct FastDouble;
+
+    impl Solution for FastDouble {
+        const YEAR: u16 = 2022;
+        const DAY: u8 = 5;
+        const VARIANT: Option<&'static str> = Some("fast");
+
+        type Input = u32;
+        type Part1 = u32;
+        type Part2 = u32;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            LineParser::new(input).number(input.trim())
+        }
+
+        fn part1(input: &Self::Input) -> Self::Part1 {
+            *input
+        }
+
+        fn part2(input: &Self::Input) -> Self::Part2 {
+            input * 2
+        }
+    }
+
+    #[test]
+    fn test_dyn_solution() {
+        let solution: &dyn DynSolution = &CountLines;
+
+        assert_eq!(solution.year(), 2023);
+        assert_eq!(solution.day(), 1);
+        assert_eq!(solution.variant(), None);
+
+        let parsed = solution.parse("a\nb\n\nc\n").unwrap();
+        assert_eq!(solution.part1(parsed.as_ref()), "3");
+        assert_eq!(solution.part2(parsed.as_ref()), "a,b,c");
+    }
+
+    #[test]
+    fn test_variant() {
+        let solution: &dyn DynSolution = &FastDouble;
+        assert_eq!(solution.variant(), Some("fast"));
+
+        let parsed = solution.parse("21\n").unwrap();
+        assert_eq!(solution.part2(parsed.as_ref()), "42");
+
+        // parse errors are handed back rather than panicking
+        assert_eq!(
+            solution.parse("x").err().unwrap().to_string(),
+            "column 1: expected a number, found \"x\""
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "different solution")]
+    fn test_mismatched_input() {
+        let parsed = FastDouble.parse("1").unwrap();
+
+        CountLines.part1(parsed.as_ref());
+    }
+}
@@ -0,0 +1,593 @@
+// an unbounded 2D map for simulations that spread out in every direction (2022 day 23's elves)
+// stored as square chunks that are only allocated once something's written to them,
+// keyed by chunk coordinates so cells far apart cost no more than cells close together
+// cells holding T::default() are empty
+use crate::geometry::Point2;
+use std::collections::{BTreeSet, HashMap};
+use std::hash::{BuildHasherDefault, Hasher};
+use std::mem;
+
+const CHUNK_BITS: i32 = 6;
+const CHUNK_SIZE: i32 = 1 << CHUNK_BITS;
+const CHUNK_MASK: i32 = CHUNK_SIZE - 1;
+const CHUNK_AREA: usize = (CHUNK_SIZE * CHUNK_SIZE) as usize;
+
+#[derive(Debug, Clone)]
+struct Chunk<T> {
+    cells: [T; CHUNK_AREA],
+    // non-empty cells, in all & in each of the chunk's columns & rows
+    len: usize,
+    columns: [u8; CHUNK_SIZE as usize],
+    rows: [u8; CHUNK_SIZE as usize],
+}
+
+// inclusive on all sides
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Bounds {
+    pub min_x: i32,
+    pub max_x: i32,
+    pub min_y: i32,
+    pub max_y: i32,
+}
+
+impl Bounds {
+    // widened, i32::MIN to i32::MAX is more than an i32 can hold
+    pub fn width(&self) -> usize {
+        (i64::from(self.max_x) - i64::from(self.min_x) + 1) as usize
+    }
+
+    pub fn height(&self) -> usize {
+        (i64::from(self.max_y) - i64::from(self.min_y) + 1) as usize
+    }
+
+    pub fn area(&self) -> usize {
+        self.width()
+            .checked_mul(self.height())
+            .expect("Bounds are too large to count the cells of")
+    }
+
+    pub fn contains(&self, position: Point2) -> bool {
+        (self.min_x..=self.max_x).contains(&position.x)
+            && (self.min_y..=self.max_y).contains(&position.y)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    chunks: HashMap<u64, Box<Chunk<T>>, FastHash>,
+    // non-empty chunks per column & row of chunks, so the bounds can shrink when cells are emptied
+    // only touched when a chunk is first filled or emptied again, not on every write
+    chunk_columns: LineCounts,
+    chunk_rows: LineCounts,
+    len: usize,
+    bounds: Option<Bounds>,
+}
+
+impl<T: Copy + Default + PartialEq> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid::new()
+    }
+}
+
+impl<T: Copy + Default + PartialEq> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid {
+            chunks: HashMap::default(),
+            chunk_columns: LineCounts::default(),
+            chunk_rows: LineCounts::default(),
+            len: 0,
+            bounds: None,
+        }
+    }
+
+    // non-empty cells
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // the smallest rectangle holding every non-empty cell, None when there aren't any
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    pub fn get(&self, position: Point2) -> T {
+        self.chunks
+            .get(&chunk_key(position))
+            .map_or_else(T::default, |chunk| chunk.cells[cell_index(position)])
+    }
+
+    pub fn set(&mut self, position: Point2, value: T) {
+        let empty = T::default();
+
+        let chunk = match self.chunks.get_mut(&chunk_key(position)) {
+            Some(chunk) => chunk,
+            // nothing to empty out there
+            None if value == empty => return,
+            None => self.chunks.entry(chunk_key(position)).or_insert_with(|| {
+                Box::new(Chunk {
+                    cells: [empty; CHUNK_AREA],
+                    len: 0,
+                    columns: [0; CHUNK_SIZE as usize],
+                    rows: [0; CHUNK_SIZE as usize],
+                })
+            }),
+        };
+        let previous = mem::replace(&mut chunk.cells[cell_index(position)], value);
+        let (column, row) = (
+            (position.x & CHUNK_MASK) as usize,
+            (position.y & CHUNK_MASK) as usize,
+        );
+        let Point2 {
+            x: chunk_x,
+            y: chunk_y,
+        } = chunk_coordinates(chunk_key(position));
+
+        match (previous != empty, value != empty) {
+            (false, true) => {
+                chunk.len += 1;
+                chunk.columns[column] += 1;
+                chunk.rows[row] += 1;
+                if chunk.len == 1 {
+                    self.chunk_columns.add(chunk_x);
+                    self.chunk_rows.add(chunk_y);
+                }
+                self.filled(position);
+            }
+            (true, false) => {
+                chunk.len -= 1;
+                chunk.columns[column] -= 1;
+                chunk.rows[row] -= 1;
+                let column_emptied = chunk.columns[column] == 0;
+                let row_emptied = chunk.rows[row] == 0;
+                if chunk.len == 0 {
+                    self.chunk_columns.remove(chunk_x);
+                    self.chunk_rows.remove(chunk_y);
+                }
+                self.emptied(position, column_emptied, row_emptied);
+            }
+            _ => {}
+        }
+    }
+
+    pub fn update(&mut self, position: Point2, update: impl FnOnce(T) -> T) {
+        let value = update(self.get(position));
+        self.set(position, value);
+    }
+
+    // in Direction8::ALL order, clockwise from north
+    // spelled out rather than mapping over Direction8::ALL, this is the hot loop of 2022 day 23
+    pub fn neighbours8(&self, position: Point2) -> [T; 8] {
+        let Point2 { x, y } = position;
+        let (cell_x, cell_y) = (x & CHUNK_MASK, y & CHUNK_MASK);
+
+        // all 8 in the same chunk, skip finding it 8 times
+        if (1..CHUNK_SIZE - 1).contains(&cell_x) && (1..CHUNK_SIZE - 1).contains(&cell_y) {
+            let Some(chunk) = self.chunks.get(&chunk_key(position)) else {
+                return [T::default(); 8];
+            };
+            let at = |dx: i32, dy: i32| chunk.cells[cell_index(Point2::new(x + dx, y + dy))];
+
+            return [
+                at(0, -1),
+                at(1, -1),
+                at(1, 0),
+                at(1, 1),
+                at(0, 1),
+                at(-1, 1),
+                at(-1, 0),
+                at(-1, -1),
+            ];
+        }
+
+        let at = |dx: i32, dy: i32| self.get(Point2::new(x + dx, y + dy));
+        [
+            at(0, -1),
+            at(1, -1),
+            at(1, 0),
+            at(1, 1),
+            at(0, 1),
+            at(-1, 1),
+            at(-1, 0),
+            at(-1, -1),
+        ]
+    }
+
+    // every non-empty cell, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Point2, T)> + '_ {
+        self.chunks
+            .iter()
+            .flat_map(|(key, chunk)| chunk.iter(chunk_coordinates(*key)))
+    }
+
+    fn filled(&mut self, position: Point2) {
+        let Point2 { x, y } = position;
+        self.len += 1;
+
+        self.bounds = Some(match self.bounds {
+            Some(bounds) => Bounds {
+                min_x: bounds.min_x.min(x),
+                max_x: bounds.max_x.max(x),
+                min_y: bounds.min_y.min(y),
+                max_y: bounds.max_y.max(y),
+            },
+            None => Bounds {
+                min_x: x,
+                max_x: x,
+                min_y: y,
+                max_y: y,
+            },
+        });
+    }
+
+    // `column_emptied` when this chunk has nothing left in the cell's column, the same for rows
+    fn emptied(&mut self, position: Point2, column_emptied: bool, row_emptied: bool) {
+        let Point2 { x, y } = position;
+        self.len -= 1;
+
+        let Some(mut bounds) = self.bounds.filter(|_| self.len > 0) else {
+            self.bounds = None;
+            return;
+        };
+        // only an edge column/row that might now be empty moves them, in to the next one that
+        // isn't, found in the outermost column/row of chunks
+        if column_emptied && x == bounds.min_x {
+            let (first, _) = self.chunk_columns.min_max();
+            bounds.min_x = self.occupied_lines(Axis::X, first).min().unwrap();
+        }
+        if column_emptied && x == bounds.max_x {
+            let (_, last) = self.chunk_columns.min_max();
+            bounds.max_x = self.occupied_lines(Axis::X, last).max().unwrap();
+        }
+        if row_emptied && y == bounds.min_y {
+            let (first, _) = self.chunk_rows.min_max();
+            bounds.min_y = self.occupied_lines(Axis::Y, first).min().unwrap();
+        }
+        if row_emptied && y == bounds.max_y {
+            let (_, last) = self.chunk_rows.min_max();
+            bounds.max_y = self.occupied_lines(Axis::Y, last).max().unwrap();
+        }
+        self.bounds = Some(bounds);
+    }
+
+    // the columns (or rows) with anything in them, within one column (or row) of chunks
+    fn occupied_lines(&self, axis: Axis, chunk_line: i32) -> impl Iterator<Item = i32> + '_ {
+        self.chunks
+            .iter()
+            .filter(move |(key, _)| axis.of(chunk_coordinates(**key)) == chunk_line)
+            .flat_map(move |(_, chunk)| {
+                let counts = match axis {
+                    Axis::X => &chunk.columns,
+                    Axis::Y => &chunk.rows,
+                };
+                (0..CHUNK_SIZE).filter(move |line| counts[*line as usize] > 0)
+            })
+            .map(move |line| (chunk_line << CHUNK_BITS) + line)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Axis {
+    X,
+    Y,
+}
+
+impl Axis {
+    fn of(self, position: Point2) -> i32 {
+        match self {
+            Axis::X => position.x,
+            Axis::Y => position.y,
+        }
+    }
+}
+
+// how many non-empty chunks are in each column (or row) of chunks, & which have any in order
+#[derive(Debug, Clone, Default)]
+struct LineCounts {
+    counts: HashMap<i32, u32, FastHash>,
+    occupied: BTreeSet<i32>,
+}
+
+impl LineCounts {
+    fn add(&mut self, line: i32) {
+        let count = self.counts.entry(line).or_insert(0);
+        *count += 1;
+        if *count == 1 {
+            self.occupied.insert(line);
+        }
+    }
+
+    fn remove(&mut self, line: i32) {
+        let count = self.counts.get_mut(&line).unwrap();
+        *count -= 1;
+        if *count == 0 {
+            self.counts.remove(&line);
+            self.occupied.remove(&line);
+        }
+    }
+
+    // panics if every line's empty
+    fn min_max(&self) -> (i32, i32) {
+        (
+            *self.occupied.first().unwrap(),
+            *self.occupied.last().unwrap(),
+        )
+    }
+}
+
+impl<T: Copy + Default + PartialEq> Chunk<T> {
+    // the non-empty cells, placed back in the whole grid
+    fn iter(&self, chunk: Point2) -> impl Iterator<Item = (Point2, T)> + '_ {
+        let Point2 {
+            x: chunk_x,
+            y: chunk_y,
+        } = chunk;
+        let empty = T::default();
+
+        self.cells
+            .iter()
+            .enumerate()
+            .filter(move |(_, value)| **value != empty)
+            .map(move |(idx, value)| {
+                let x = (chunk_x << CHUNK_BITS) + (idx as i32 & CHUNK_MASK);
+                let y = (chunk_y << CHUNK_BITS) + (idx as i32 >> CHUNK_BITS);
+                (Point2::new(x, y), *value)
+            })
+    }
+}
+
+// keys here are small ints, std's default hasher is built to resist attacks & costs more than
+// the rest of a lookup, this is FxHash's multiply & rotate
+type FastHash = BuildHasherDefault<FastHasher>;
+
+#[derive(Default)]
+struct FastHasher {
+    hash: u64,
+}
+
+impl Hasher for FastHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.write_u64(u64::from(*byte));
+        }
+    }
+
+    fn write_i32(&mut self, value: i32) {
+        self.write_u64(value as u32 as u64);
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.hash = (self.hash.rotate_left(5) ^ value).wrapping_mul(0x51_7c_c1_b7_27_22_0a_95);
+    }
+
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+}
+
+// the chunk a cell is in, shifting rounds towards negative infinity so -1 is in chunk -1
+// both coordinates packed into one u64, so a lookup hashes a single value
+fn chunk_key(position: Point2) -> u64 {
+    let (chunk_x, chunk_y) = (position.x >> CHUNK_BITS, position.y >> CHUNK_BITS);
+
+    (u64::from(chunk_x as u32) << 32) | u64::from(chunk_y as u32)
+}
+
+fn chunk_coordinates(key: u64) -> Point2 {
+    Point2::new((key >> 32) as u32 as i32, key as u32 as i32)
+}
+
+fn cell_index(position: Point2) -> usize {
+    ((position.x & CHUNK_MASK) + (position.y & CHUNK_MASK) * CHUNK_SIZE) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Direction8;
+
+    #[test]
+    fn test_get_set() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.get(Point2::ORIGIN), 0u8);
+
+        // spread out in every direction, across chunk boundaries
+        let positions = [
+            Point2::new(0, 0),
+            Point2::new(-1, -1),
+            Point2::new(63, 64),
+            Point2::new(-300, 7),
+            Point2::new(42, -1000),
+            Point2::new(1000, 1000),
+        ];
+        for (idx, position) in positions.iter().enumerate() {
+            grid.set(*position, idx as u8 + 1);
+        }
+        for (idx, position) in positions.iter().enumerate() {
+            assert_eq!(grid.get(*position), idx as u8 + 1);
+        }
+        assert_eq!(grid.get(Point2::new(1, 0)), 0);
+        assert_eq!(grid.get(Point2::new(-301, 7)), 0);
+        assert_eq!(grid.len(), positions.len());
+
+        let mut found: Vec<(Point2, u8)> = grid.iter().collect();
+        found.sort();
+        assert_eq!(found[0], (Point2::new(-300, 7), 4));
+        assert_eq!(found.len(), positions.len());
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+
+        grid.set(Point2::new(2, 3), true);
+        grid.set(Point2::new(-20, 40), true);
+        grid.set(Point2::new(5, -1), true);
+        assert_eq!(
+            grid.bounds(),
+            Some(Bounds {
+                min_x: -20,
+                max_x: 5,
+                min_y: -1,
+                max_y: 40
+            })
+        );
+        assert_eq!(grid.bounds().unwrap().area(), 26 * 42);
+
+        // shrinks back when the edges are emptied
+        grid.set(Point2::new(-20, 40), false);
+        assert_eq!(
+            grid.bounds(),
+            Some(Bounds {
+                min_x: 2,
+                max_x: 5,
+                min_y: -1,
+                max_y: 3
+            })
+        );
+        grid.set(Point2::new(2, 3), false);
+        grid.set(Point2::new(5, -1), false);
+        assert_eq!(grid.bounds(), None);
+        assert!(grid.is_empty());
+
+        // writing empty cells doesn't count as filling them
+        grid.set(Point2::new(100, 100), false);
+        assert_eq!(grid.bounds(), None);
+    }
+
+    #[test]
+    fn test_bounds_shrink_on_moves() {
+        let mut grid = SparseGrid::new();
+        // the outermost elf is alone in its chunk, another elf shares its column further down
+        for (x, y) in [(-65, 0), (10, 5), (20, 70), (-65, 200)] {
+            grid.set(Point2::new(x, y), true);
+        }
+        let move_cell = |grid: &mut SparseGrid<bool>, from: (i32, i32), to: (i32, i32)| {
+            grid.set(Point2::new(from.0, from.1), false);
+            grid.set(Point2::new(to.0, to.1), true);
+        };
+
+        // still someone in the column
+        move_cell(&mut grid, (-65, 0), (-60, 0));
+        assert_eq!(grid.bounds().unwrap().min_x, -65);
+
+        // in to the next chunk along, & then the next column over within it
+        move_cell(&mut grid, (-65, 200), (-3, 200));
+        assert_eq!(grid.bounds().unwrap().min_x, -60);
+        move_cell(&mut grid, (-60, 0), (0, 0));
+        move_cell(&mut grid, (-3, 200), (15, 60));
+        assert_eq!(
+            grid.bounds(),
+            Some(Bounds {
+                min_x: 0,
+                max_x: 20,
+                min_y: 0,
+                max_y: 70
+            })
+        );
+
+        // the bottom elf moves up past another
+        move_cell(&mut grid, (20, 70), (20, 3));
+        assert_eq!(
+            grid.bounds(),
+            Some(Bounds {
+                min_x: 0,
+                max_x: 20,
+                min_y: 0,
+                max_y: 60
+            })
+        );
+    }
+
+    #[test]
+    fn test_bounds_match_the_cells() {
+        let mut grid = SparseGrid::new();
+        // a fixed pseudo random walk, checked against bounds found from every cell
+        let mut seed = 12345u32;
+        let mut next = |range: i32| {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            (seed >> 16) as i32 % range - range / 2
+        };
+        for _ in 0..500 {
+            let position = Point2::new(next(300), next(300));
+            grid.set(position, next(2) == 0);
+
+            let cells: Vec<Point2> = grid.iter().map(|(position, _)| position).collect();
+            let expected = (!cells.is_empty()).then(|| Bounds {
+                min_x: cells.iter().map(|p| p.x).min().unwrap(),
+                max_x: cells.iter().map(|p| p.x).max().unwrap(),
+                min_y: cells.iter().map(|p| p.y).min().unwrap(),
+                max_y: cells.iter().map(|p| p.y).max().unwrap(),
+            });
+            assert_eq!(grid.bounds(), expected);
+            assert_eq!(grid.len(), cells.len());
+        }
+    }
+
+    #[test]
+    fn test_update() {
+        let mut grid = SparseGrid::new();
+        grid.update(Point2::new(-5, 5), |count: u8| count + 1);
+        grid.update(Point2::new(-5, 5), |count| count + 1);
+
+        assert_eq!(grid.get(Point2::new(-5, 5)), 2);
+        assert_eq!(grid.len(), 1);
+    }
+
+    #[test]
+    fn test_neighbours8() {
+        let mut grid = SparseGrid::new();
+        // one in the middle of a chunk, one on a chunk's corner
+        for (x, y) in [(5, 5), (0, 0)] {
+            let position = Point2::new(x, y);
+            grid.set(position + Direction8::North, 'N');
+            grid.set(position + Direction8::East, 'E');
+            grid.set(position + Direction8::SouthWest, 'S');
+            grid.set(position + Direction8::NorthWest, 'W');
+
+            assert_eq!(
+                grid.neighbours8(position),
+                ['N', '\0', 'E', '\0', '\0', 'S', '\0', 'W']
+            );
+        }
+        assert_eq!(grid.neighbours8(Point2::new(100, 100)), ['\0'; 8]);
+    }
+
+    #[test]
+    fn test_far_apart() {
+        let mut grid = SparseGrid::new();
+        let corners = [
+            Point2::new(i32::MIN, i32::MIN),
+            Point2::new(i32::MAX, i32::MAX),
+            Point2::new(-1_000_000, 1_000_000),
+            Point2::new(1_000_000, -1_000_000),
+        ];
+        for corner in corners {
+            grid.set(corner, true);
+        }
+
+        // only the 4 chunks written to are allocated
+        assert_eq!(grid.chunks.len(), 4);
+        assert!(corners.iter().all(|corner| grid.get(*corner)));
+        assert_eq!(grid.iter().count(), 4);
+        assert_eq!(grid.bounds().unwrap().width(), 1 << 32);
+
+        grid.set(corners[0], false);
+        grid.set(corners[1], false);
+        let bounds = grid.bounds().unwrap();
+        assert_eq!(
+            bounds,
+            Bounds {
+                min_x: -1_000_000,
+                max_x: 1_000_000,
+                min_y: -1_000_000,
+                max_y: 1_000_000
+            }
+        );
+        assert_eq!(bounds.area(), 2_000_001 * 2_000_001);
+    }
+}
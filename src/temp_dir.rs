@@ -0,0 +1,34 @@
+// a scratch directory for tests that touch the filesystem, removed again when it's dropped
+// so a failing assert doesn't leave it behind
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    // tests run in parallel, so each one needs its own name
+    pub fn new(name: &str) -> TempDir {
+        let path = std::env::temp_dir().join(format!("aoc_{name}_{}", std::process::id()));
+        // left over from a run that was killed before it could clean up
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+
+        TempDir { path }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn join(&self, path: impl AsRef<Path>) -> PathBuf {
+        self.path.join(path)
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}